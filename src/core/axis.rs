@@ -0,0 +1,268 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::{Field, MulAdd};
+use std::ops;
+
+/// Which lines of a matrix a reduction runs along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Reduce each row to one value: the result has one element per row.
+    Rows,
+    /// Reduce each column to one value: the result has one element per column.
+    Columns,
+}
+
+/// A borrowed row or column of a matrix.
+#[derive(Debug, Clone, Copy)]
+pub struct VectorView<'a, K: Field> {
+    matrix: &'a Matrix<K>,
+    axis: Axis,
+    index: usize,
+}
+
+impl<'a, K: Field> VectorView<'a, K> {
+    pub fn len(&self) -> usize {
+        match self.axis {
+            Axis::Rows => self.matrix.shape().0,
+            Axis::Columns => self.matrix.shape().1,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = K> + 'a {
+        let view = *self;
+        (0..self.len()).map(move |i| view[i])
+    }
+
+    pub fn to_vector(self) -> Vector<K> {
+        self.iter().collect()
+    }
+
+    pub fn dot(&self, other: &Vector<K>) -> K {
+        assert_eq!(self.len(), other.size(), "Vector dot size mismatch.");
+        self.iter().zip(other.iter()).fold(K::zero(), |acc, (a, b)| MulAdd::mul_add(a, b, acc))
+    }
+}
+
+impl<K: Field> ops::Index<usize> for VectorView<'_, K> {
+    type Output = K;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match self.axis {
+            Axis::Rows => &self.matrix[(i, self.index)],
+            Axis::Columns => &self.matrix[(self.index, i)],
+        }
+    }
+}
+
+/// Index of the first element `better` prefers over every other, or `None` for an empty line.
+fn arg_best<K: Field>(values: impl Iterator<Item = K>, better: impl Fn(K, K) -> bool) -> Option<usize> {
+    values
+        .enumerate()
+        .fold(None, |best: Option<(usize, K)>, (i, value)| match best {
+            Some((_, current)) if !better(value, current) => best,
+            _ => Some((i, value)),
+        })
+        .map(|(i, _)| i)
+}
+
+impl<K: Field> Matrix<K> {
+    pub fn row(&self, y: usize) -> VectorView<'_, K> {
+        assert!(y < self.shape().1);
+        VectorView { matrix: self, axis: Axis::Rows, index: y }
+    }
+
+    pub fn column(&self, x: usize) -> VectorView<'_, K> {
+        assert!(x < self.shape().0);
+        VectorView { matrix: self, axis: Axis::Columns, index: x }
+    }
+
+    pub fn row_iter(&self) -> impl Iterator<Item = VectorView<'_, K>> {
+        (0..self.shape().1).map(|y| self.row(y))
+    }
+
+    pub fn column_iter(&self) -> impl Iterator<Item = VectorView<'_, K>> {
+        (0..self.shape().0).map(|x| self.column(x))
+    }
+
+    /// The lines that `axis` reduces, in order.
+    pub fn lines(&self, axis: Axis) -> impl Iterator<Item = VectorView<'_, K>> {
+        let count = match axis {
+            Axis::Rows => self.shape().1,
+            Axis::Columns => self.shape().0,
+        };
+        (0..count).map(move |index| VectorView { matrix: self, axis, index })
+    }
+
+    pub fn sum(&self, axis: Axis) -> Vector<K> {
+        self.lines(axis).map(|line| line.iter().fold(K::zero(), |acc, v| acc + v)).collect()
+    }
+
+    /// The sum of each row.
+    pub fn sum_rows(&self) -> Vector<K> {
+        self.sum(Axis::Rows)
+    }
+
+    /// The sum of each column.
+    pub fn sum_columns(&self) -> Vector<K> {
+        self.sum(Axis::Columns)
+    }
+
+    pub fn product(&self, axis: Axis) -> Vector<K> {
+        self.lines(axis).map(|line| line.iter().fold(K::one(), |acc, v| acc * v)).collect()
+    }
+
+    /// Integer element types truncate the division.
+    pub fn mean(&self, axis: Axis) -> Vector<K> {
+        self.lines(axis)
+            .map(|line| {
+                assert!(!line.is_empty(), "Mean of an empty line is undefined");
+                let count = line.iter().fold(K::zero(), |acc, _| acc + K::one());
+                line.iter().fold(K::zero(), |acc, v| acc + v) / count
+            })
+            .collect()
+    }
+
+    pub fn min(&self, axis: Axis) -> Vector<K> {
+        self.argmin(axis).into_iter().zip(self.lines(axis)).map(|(i, line)| line[i]).collect()
+    }
+
+    pub fn max(&self, axis: Axis) -> Vector<K> {
+        self.argmax(axis).into_iter().zip(self.lines(axis)).map(|(i, line)| line[i]).collect()
+    }
+
+    /// Position of the minimum along each line; ties go to the first.
+    pub fn argmin(&self, axis: Axis) -> Vec<usize> {
+        self.lines(axis)
+            .map(|line| arg_best(line.iter(), |a, b| a < b).expect("Minimum of an empty line is undefined"))
+            .collect()
+    }
+
+    /// Position of the maximum along each line; ties go to the first.
+    pub fn argmax(&self, axis: Axis) -> Vec<usize> {
+        self.lines(axis)
+            .map(|line| arg_best(line.iter(), |a, b| a > b).expect("Maximum of an empty line is undefined"))
+            .collect()
+    }
+
+    /// Running sums along each line, keeping the shape.
+    pub fn cumsum(&self, axis: Axis) -> Matrix<K> {
+        let mut result = self.clone();
+        let (width, height) = self.shape();
+
+        match axis {
+            Axis::Rows => {
+                for y in 0..height {
+                    for x in 1..width {
+                        result[(x, y)] = result[(x, y)] + result[(x - 1, y)];
+                    }
+                }
+            }
+            Axis::Columns => {
+                for x in 0..width {
+                    for y in 1..height {
+                        result[(x, y)] = result[(x, y)] + result[(x, y - 1)];
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_views() {
+        let a = Matrix::from_rows([
+            [3, -1, 4],
+            [1, 5, -9],
+        ]);
+        assert_eq!(a.row(1).to_vector(), Vector::from([1, 5, -9]));
+        assert_eq!(a.column(2).to_vector(), Vector::from([4, -9]));
+        assert_eq!(a.row(0)[2], 4);
+        assert_eq!(a.column(0).len(), 2);
+        assert_eq!(a.row(0).dot(&Vector::from([1, 1, 1])), 6);
+
+        let rows: Vec<Vec<i32>> = a.row_iter().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, [[3, -1, 4], [1, 5, -9]]);
+        let columns: Vec<Vector<i32>> = a.column_iter().map(|column| column.to_vector()).collect();
+        assert_eq!(columns, [Vector::from([3, 1]), Vector::from([-1, 5]), Vector::from([4, -9])]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_view_out_of_bounds() {
+        let a = Matrix::from_rows([
+            [3, -1, 4],
+            [1, 5, -9],
+        ]);
+        a.column(3);
+    }
+
+    #[test]
+    fn test_sums() {
+        let a = Matrix::from_rows([
+            [3, -1, 4],
+            [1, 5, -9],
+        ]);
+        assert_eq!(a.sum_rows(), Vector::from([6, -3]));
+        assert_eq!(a.sum_columns(), Vector::from([4, 4, -5]));
+        assert_eq!(a.product(Axis::Rows), Vector::from([-12, -45]));
+        assert_eq!(a.product(Axis::Columns), Vector::from([3, -5, -36]));
+        assert_eq!(Matrix::<f64>::from_elem(1., 0, 2).sum_rows(), Vector::from([0., 0.]));
+    }
+
+    #[test]
+    fn test_mean() {
+        let a = Matrix::from_rows([
+            [1., 2., 6.],
+            [-4., 0., 1.],
+        ]);
+        assert_eq!(a.mean(Axis::Rows), Vector::from([3., -1.]));
+        assert_eq!(a.mean(Axis::Columns), Vector::from([-1.5, 1., 3.5]));
+    }
+
+    #[test]
+    fn test_extrema() {
+        let a = Matrix::from_rows([
+            [3, -1, 4],
+            [1, 5, -9],
+        ]);
+        assert_eq!(a.min(Axis::Rows), Vector::from([-1, -9]));
+        assert_eq!(a.max(Axis::Rows), Vector::from([4, 5]));
+        assert_eq!(a.argmin(Axis::Columns), [1, 0, 1]);
+        assert_eq!(a.argmax(Axis::Columns), [0, 1, 0]);
+        assert_eq!(Matrix::from_rows([[2, 7, 7, 2]]).argmax(Axis::Rows), [1]);
+        assert_eq!(Matrix::from_rows([[2, 7, 7, 2]]).argmin(Axis::Rows), [0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_extrema_empty() {
+        Matrix::<f32>::from_elem(0., 0, 2).max(Axis::Rows);
+    }
+
+    #[test]
+    fn test_cumsum() {
+        let a = Matrix::from_rows([
+            [3, -1, 4],
+            [1, 5, -9],
+        ]);
+        assert_eq!(a.cumsum(Axis::Rows), Matrix::from_rows([
+            [3, 2, 6],
+            [1, 6, -3],
+        ]));
+        assert_eq!(a.cumsum(Axis::Columns), Matrix::from_rows([
+            [3, -1, 4],
+            [4, 4, -5],
+        ]));
+    }
+}
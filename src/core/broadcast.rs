@@ -0,0 +1,208 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::Field;
+use std::ops;
+
+/// A vector stretched across a matrix for elementwise arithmetic, e.g. centering
+/// the columns of `data` with `&data - Broadcast::rows(&data.mean(Axis::Columns))`.
+#[derive(Debug, Clone, Copy)]
+pub enum Broadcast<'a, K: Field> {
+    /// Applied to every row; the vector has one element per column.
+    Rows(&'a Vector<K>),
+    /// Applied to every column; the vector has one element per row.
+    Columns(&'a Vector<K>),
+}
+
+impl<'a, K: Field> Broadcast<'a, K> {
+    pub fn rows(vector: &'a Vector<K>) -> Self {
+        Broadcast::Rows(vector)
+    }
+
+    pub fn columns(vector: &'a Vector<K>) -> Self {
+        Broadcast::Columns(vector)
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    /// Combines every row with `vector`: element `(x, y)` becomes `f(self[(x, y)], vector[x])`.
+    pub fn broadcast_rows<F: FnMut(K, K) -> K>(&self, vector: &Vector<K>, mut f: F) -> Matrix<K> {
+        let (width, height) = self.shape();
+        assert_eq!(vector.size(), width, "Vector size must equal matrix width");
+        Matrix::from_fn(width, height, |x, y| f(self[(x, y)], vector[x]))
+    }
+
+    /// Combines every column with `vector`: element `(x, y)` becomes `f(self[(x, y)], vector[y])`.
+    pub fn broadcast_columns<F: FnMut(K, K) -> K>(&self, vector: &Vector<K>, mut f: F) -> Matrix<K> {
+        let (width, height) = self.shape();
+        assert_eq!(vector.size(), height, "Vector size must equal matrix height");
+        Matrix::from_fn(width, height, |x, y| f(self[(x, y)], vector[y]))
+    }
+
+    fn broadcast<F: FnMut(K, K) -> K>(&self, broadcast: Broadcast<'_, K>, f: F) -> Matrix<K> {
+        match broadcast {
+            Broadcast::Rows(vector) => self.broadcast_rows(vector, f),
+            Broadcast::Columns(vector) => self.broadcast_columns(vector, f),
+        }
+    }
+}
+
+macro_rules! impl_broadcast_op {
+    ($($trait:ident, $method:ident, $op:tt);*) => {
+        $(
+        impl<K: Field> ops::$trait<Broadcast<'_, K>> for &Matrix<K> {
+            type Output = Matrix<K>;
+
+            fn $method(self, rhs: Broadcast<'_, K>) -> Self::Output {
+                self.broadcast(rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<K: Field> ops::$trait<Broadcast<'_, K>> for Matrix<K> {
+            type Output = Matrix<K>;
+
+            fn $method(self, rhs: Broadcast<'_, K>) -> Self::Output {
+                self.broadcast(rhs, |a, b| a $op b)
+            }
+        }
+        )*
+    };
+}
+
+impl_broadcast_op!(Add, add, +; Sub, sub, -; Mul, mul, *; Div, div, /);
+
+impl<K: Field> ops::Add<K> for Matrix<K> {
+    type Output = Self;
+
+    fn add(mut self, rhs: K) -> Self::Output {
+        self.map_inplace(|value| value + rhs);
+        self
+    }
+}
+
+impl<K: Field> ops::Sub<K> for Matrix<K> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: K) -> Self::Output {
+        self.map_inplace(|value| value - rhs);
+        self
+    }
+}
+
+// Scalar on the left; coherence rules out a blanket impl over `K`
+macro_rules! impl_scalar_lhs {
+    ($($t:ty),*) => {
+        $(
+        impl ops::Add<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn add(self, rhs: Matrix<$t>) -> Self::Output {
+                rhs + self
+            }
+        }
+
+        impl ops::Sub<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn sub(self, mut rhs: Matrix<$t>) -> Self::Output {
+                rhs.map_inplace(|value| self - value);
+                rhs
+            }
+        }
+
+        impl ops::Mul<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn mul(self, rhs: Matrix<$t>) -> Self::Output {
+                rhs * self
+            }
+        }
+        )*
+    };
+}
+
+impl_scalar_lhs!(f32, f64, i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Axis;
+
+    #[test]
+    fn test_broadcast_rows() {
+        let a = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let v = Vector::from([10., 20., 30.]);
+        assert_eq!(&a + Broadcast::rows(&v), Matrix::from_rows([
+            [11., 22., 33.],
+            [14., 25., 36.],
+        ]));
+        assert_eq!(&a * Broadcast::rows(&v), Matrix::from_rows([
+            [10., 40., 90.],
+            [40., 100., 180.],
+        ]));
+        assert_eq!(a.broadcast_rows(&v, |x, y| y - x), Matrix::from_rows([
+            [9., 18., 27.],
+            [6., 15., 24.],
+        ]));
+    }
+
+    #[test]
+    fn test_broadcast_columns() {
+        let a = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let v = Vector::from([1., 2.]);
+        assert_eq!(&a - Broadcast::columns(&v), Matrix::from_rows([
+            [0., 1., 2.],
+            [2., 3., 4.],
+        ]));
+        assert_eq!(a / Broadcast::columns(&v), Matrix::from_rows([
+            [1., 2., 3.],
+            [2., 2.5, 3.],
+        ]));
+    }
+
+    #[test]
+    fn test_center_columns() {
+        let data = Matrix::from_rows([
+            [1., 10.],
+            [3., 30.],
+            [5., 20.],
+        ]);
+        let centered = &data - Broadcast::rows(&data.mean(Axis::Columns));
+        assert_eq!(centered, Matrix::from_rows([
+            [-2., -10.],
+            [0., 10.],
+            [2., 0.],
+        ]));
+        assert_eq!(centered.sum_columns(), Vector::from([0., 0.]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_broadcast_size_mismatch() {
+        let a = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let _ = a + Broadcast::rows(&Vector::from([1., 2.]));
+    }
+
+    #[test]
+    fn test_scalar_arithmetic() {
+        let a = Matrix::from_rows([
+            [1, 2],
+            [3, 4],
+        ]);
+        assert_eq!(a.clone() + 1, Matrix::from_rows([[2, 3], [4, 5]]));
+        assert_eq!(1 + a.clone(), Matrix::from_rows([[2, 3], [4, 5]]));
+        assert_eq!(a.clone() - 1, Matrix::from_rows([[0, 1], [2, 3]]));
+        assert_eq!(10 - a.clone(), Matrix::from_rows([[9, 8], [7, 6]]));
+        assert_eq!(2 * a, Matrix::from_rows([[2, 4], [6, 8]]));
+        assert_eq!(0.5 + Matrix::from_rows([[1., 2., 3.], [4., 5., 6.]]), Matrix::from_rows([[1.5, 2.5, 3.5], [4.5, 5.5, 6.5]]));
+    }
+}
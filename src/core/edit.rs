@@ -0,0 +1,235 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::Field;
+use std::mem;
+
+impl<K: Field> Matrix<K> {
+    /// Inserts `row` so that it becomes row `y`, shifting the rows below it down.
+    pub fn insert_row(&mut self, y: usize, row: &Vector<K>) {
+        let (width, height) = self.shape();
+        assert!(y <= height, "Row index out of bounds");
+        assert_eq!(row.size(), width, "Row size must equal matrix width");
+
+        *self = Matrix::from_fn(width, height + 1, |x, r| match r {
+            r if r < y => self[(x, r)],
+            r if r == y => row[x],
+            r => self[(x, r - 1)],
+        });
+    }
+
+    /// Inserts `column` so that it becomes column `x`, shifting the columns after it right.
+    pub fn insert_column(&mut self, x: usize, column: &Vector<K>) {
+        let (width, height) = self.shape();
+        assert!(x <= width, "Column index out of bounds");
+        assert_eq!(column.size(), height, "Column size must equal matrix height");
+
+        let mut data = mem::take(self).into_vec();
+        data.splice(x * height..x * height, column.iter());
+        *self = Matrix::from_column_major_vec(width + 1, height, data);
+    }
+
+    pub fn remove_row(&mut self, y: usize) -> Vector<K> {
+        let (width, height) = self.shape();
+        assert!(y < height, "Row index out of bounds");
+
+        let row = Vector::from_fn(width, |x| self[(x, y)]);
+        *self = Matrix::from_fn(width, height - 1, |x, r| self[(x, if r < y { r } else { r + 1 })]);
+        row
+    }
+
+    pub fn remove_column(&mut self, x: usize) -> Vector<K> {
+        let (width, height) = self.shape();
+        assert!(x < width, "Column index out of bounds");
+
+        let mut data = mem::take(self).into_vec();
+        let column = data.drain(x * height..(x + 1) * height).collect();
+        *self = Matrix::from_column_major_vec(width - 1, height, data);
+        column
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        let (width, height) = self.shape();
+        assert!(a < height && b < height, "Row index out of bounds");
+
+        for x in 0..width {
+            self.as_mut_slice().swap(x * height + a, x * height + b);
+        }
+    }
+
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        let (width, height) = self.shape();
+        assert!(a < width && b < width, "Column index out of bounds");
+
+        for y in 0..height {
+            self.as_mut_slice().swap(a * height + y, b * height + y);
+        }
+    }
+
+    /// Grows or shrinks to `width`x`height`, keeping the top-left elements and filling new ones.
+    pub fn resize(&mut self, width: usize, height: usize, fill: K) {
+        let (old_width, old_height) = self.shape();
+
+        *self = Matrix::from_fn(width, height, |x, y| {
+            if x < old_width && y < old_height { self[(x, y)] } else { fill }
+        });
+    }
+
+    /// Reinterprets the elements as a `width`x`height` matrix, keeping their column-major order.
+    pub fn reshape(&mut self, width: usize, height: usize) {
+        let (old_width, old_height) = self.shape();
+        assert_eq!(old_width * old_height, width * height, "Reshape must keep the number of elements");
+
+        *self = Matrix::from_column_major_vec(width, height, mem::take(self).into_vec());
+    }
+
+    /// The elements in column-major order.
+    pub fn flatten(&self) -> Vector<K> {
+        Vector::from(self.as_slice().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_row() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        m.insert_row(1, &Vector::from([7, 8, 9]));
+        assert_eq!(m, Matrix::from_rows([
+            [1, 2, 3],
+            [7, 8, 9],
+            [4, 5, 6],
+        ]));
+        m.insert_row(3, &Vector::from([0, 0, 0]));
+        assert_eq!(m.row(3).to_vector(), Vector::from([0, 0, 0]));
+
+        let mut empty = Matrix::from_elem(0, 2, 0);
+        empty.insert_row(0, &Vector::from([1, 2]));
+        assert_eq!(empty, Matrix::from_rows([[1, 2]]));
+    }
+
+    #[test]
+    fn test_insert_column() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        m.insert_column(0, &Vector::from([-1, -4]));
+        m.insert_column(4, &Vector::from([10, 20]));
+        assert_eq!(m, Matrix::from_rows([
+            [-1, 1, 2, 3, 10],
+            [-4, 4, 5, 6, 20],
+        ]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_column_size_mismatch() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        m.insert_column(1, &Vector::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        assert_eq!(m.remove_row(0), Vector::from([1, 2, 3]));
+        assert_eq!(m, Matrix::from_rows([[4, 5, 6]]));
+
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        assert_eq!(m.remove_column(1), Vector::from([2, 5]));
+        assert_eq!(m, Matrix::from_rows([
+            [1, 3],
+            [4, 6],
+        ]));
+        m.remove_column(0);
+        m.remove_column(0);
+        assert_eq!(m.shape(), (0, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_row_out_of_bounds() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        m.remove_row(2);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        m.swap_rows(0, 1);
+        assert_eq!(m, Matrix::from_rows([
+            [4, 5, 6],
+            [1, 2, 3],
+        ]));
+        m.swap_columns(0, 2);
+        m.swap_columns(1, 1);
+        assert_eq!(m, Matrix::from_rows([
+            [6, 5, 4],
+            [3, 2, 1],
+        ]));
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        m.resize(4, 3, 0);
+        assert_eq!(m, Matrix::from_rows([
+            [1, 2, 3, 0],
+            [4, 5, 6, 0],
+            [0, 0, 0, 0],
+        ]));
+        m.resize(2, 1, 9);
+        assert_eq!(m, Matrix::from_rows([[1, 2]]));
+    }
+
+    #[test]
+    fn test_reshape_flatten() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        assert_eq!(m.flatten(), Vector::from([1, 4, 2, 5, 3, 6]));
+
+        m.reshape(2, 3);
+        assert_eq!(m, Matrix::from_rows([
+            [1, 5],
+            [4, 3],
+            [2, 6],
+        ]));
+        m.reshape(6, 1);
+        assert_eq!(m, Matrix::from_rows([[1, 4, 2, 5, 3, 6]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reshape_size_mismatch() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        m.reshape(4, 2);
+    }
+}
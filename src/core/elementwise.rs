@@ -0,0 +1,215 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::Field;
+
+impl<K: Field> Matrix<K> {
+    pub fn map<L: Field, F: FnMut(K) -> L>(&self, f: F) -> Matrix<L> {
+        let (width, height) = self.shape();
+        Matrix::from_column_major_vec(width, height, self.iter().map(f).collect())
+    }
+
+    pub fn map_inplace<F: FnMut(K) -> K>(&mut self, mut f: F) {
+        for value in self.iter_mut() {
+            *value = f(*value);
+        }
+    }
+
+    pub fn zip_map<L: Field, M: Field, F: FnMut(K, L) -> M>(&self, other: &Matrix<L>, mut f: F) -> Matrix<M> {
+        assert_eq!(self.shape(), other.shape(), "Matrix elementwise dimensions mismatch.");
+        let (width, height) = self.shape();
+        Matrix::from_column_major_vec(width, height, self.iter().zip(other.iter()).map(|(a, b)| f(a, b)).collect())
+    }
+
+    /// Folds over the elements in column-major order.
+    pub fn fold<B, F: FnMut(B, K) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Elementwise product.
+    pub fn hadamard(&self, other: &Matrix<K>) -> Matrix<K> {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    pub fn elementwise_div(&self, other: &Matrix<K>) -> Matrix<K> {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    pub fn any<F: FnMut(K) -> bool>(&self, f: F) -> bool {
+        self.iter().any(f)
+    }
+
+    pub fn all<F: FnMut(K) -> bool>(&self, f: F) -> bool {
+        self.iter().all(f)
+    }
+
+    /// Elements in column-major (storage) order.
+    pub fn iter(&self) -> impl Iterator<Item = K> + '_ {
+        self.as_slice().iter().copied()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut K> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    pub fn iter_row_major(&self) -> impl Iterator<Item = K> + '_ {
+        let (width, height) = self.shape();
+        (0..height).flat_map(move |y| (0..width).map(move |x| self[(x, y)]))
+    }
+
+    pub fn iter_row_major_mut(&mut self) -> impl Iterator<Item = &mut K> {
+        let (width, height) = self.shape();
+        // One cursor per column, advanced in turn
+        let mut columns: Vec<_> = self.as_mut_slice().chunks_mut(height.max(1)).map(<[K]>::iter_mut).collect();
+        (0..width * height).filter_map(move |i| columns[i % width].next())
+    }
+}
+
+impl<K: Field> Vector<K> {
+    pub fn map<L: Field, F: FnMut(K) -> L>(&self, f: F) -> Vector<L> {
+        self.iter().map(f).collect()
+    }
+
+    pub fn map_inplace<F: FnMut(K) -> K>(&mut self, mut f: F) {
+        for value in self.iter_mut() {
+            *value = f(*value);
+        }
+    }
+
+    pub fn zip_map<L: Field, M: Field, F: FnMut(K, L) -> M>(&self, other: &Vector<L>, mut f: F) -> Vector<M> {
+        assert_eq!(self.size(), other.size(), "Vector elementwise size mismatch.");
+        self.iter().zip(other.iter()).map(|(a, b)| f(a, b)).collect()
+    }
+
+    pub fn fold<B, F: FnMut(B, K) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    pub fn hadamard(&self, other: &Vector<K>) -> Vector<K> {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    pub fn elementwise_div(&self, other: &Vector<K>) -> Vector<K> {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    pub fn any<F: FnMut(K) -> bool>(&self, f: F) -> bool {
+        self.iter().any(f)
+    }
+
+    pub fn all<F: FnMut(K) -> bool>(&self, f: F) -> bool {
+        self.iter().all(f)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = K> + '_ {
+        self.as_slice().iter().copied()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut K> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_map() {
+        let mut a = Matrix::from_rows([
+            [1, -2, 3],
+            [-4, 5, -6],
+        ]);
+        assert_eq!(a.map(|v| v as f64 * 0.5), Matrix::from_rows([
+            [0.5, -1., 1.5],
+            [-2., 2.5, -3.],
+        ]));
+
+        a.map_inplace(|v| v * v);
+        assert_eq!(a, Matrix::from_rows([
+            [1, 4, 9],
+            [16, 25, 36],
+        ]));
+    }
+
+    #[test]
+    fn test_matrix_zip_map() {
+        let a = Matrix::from_rows([
+            [1., 2.],
+            [3., 4.],
+        ]);
+        let b = Matrix::from_rows([
+            [2., 4.],
+            [6., 8.],
+        ]);
+        assert_eq!(a.hadamard(&b), Matrix::from_rows([
+            [2., 8.],
+            [18., 32.],
+        ]));
+        assert_eq!(b.elementwise_div(&a), Matrix::from_elem(2., 2, 2));
+        assert_eq!(a.zip_map(&b, |x, y| y - x), a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_zip_map_panic() {
+        let a = Matrix::from_elem(1, 2, 3);
+        let b = Matrix::from_elem(1, 3, 2);
+        let _ = a.hadamard(&b);
+    }
+
+    #[test]
+    fn test_matrix_fold() {
+        let a = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        assert_eq!(a.fold(0, |acc, v| acc + v), 21);
+        assert_eq!(a.fold(Vec::new(), |mut acc, v| { acc.push(v); acc }), vec![1, 4, 2, 5, 3, 6]);
+        assert!(a.any(|v| v > 5));
+        assert!(!a.any(|v| v > 6));
+        assert!(a.all(|v| v > 0));
+        assert!(!a.all(|v| v > 1));
+    }
+
+    #[test]
+    fn test_matrix_iter() {
+        let mut a = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        assert_eq!(a.iter().collect::<Vec<_>>(), [1, 4, 2, 5, 3, 6]);
+        assert_eq!(a.iter_row_major().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+
+        for (i, value) in a.iter_row_major_mut().enumerate() {
+            *value = 10 * i as i32;
+        }
+        assert_eq!(a, Matrix::from_rows([
+            [0, 10, 20],
+            [30, 40, 50],
+        ]));
+
+        for value in a.iter_mut() {
+            *value += 1;
+        }
+        assert_eq!(a.iter().collect::<Vec<_>>(), [1, 31, 11, 41, 21, 51]);
+        assert_eq!(Matrix::<i32>::from_elem(0, 3, 0).iter_row_major_mut().count(), 0);
+    }
+
+    #[test]
+    fn test_vector_elementwise() {
+        let mut u = Vector::from([1., 2., 3.]);
+        let v = Vector::from([4., 5., 6.]);
+        assert_eq!(u.hadamard(&v), Vector::from([4., 10., 18.]));
+        assert_eq!(v.elementwise_div(&u), Vector::from([4., 2.5, 2.]));
+        assert_eq!(u.map(|x| x as i32 * 2), Vector::from([2, 4, 6]));
+        assert_eq!(u.fold(0., |acc, x| acc + x), 6.);
+        assert!(u.all(|x| x > 0.) && !u.any(|x| x > 3.));
+
+        u.map_inplace(|x| -x);
+        for value in u.iter_mut().skip(1) {
+            *value *= 10.;
+        }
+        assert_eq!(u.iter().collect::<Vec<_>>(), [-1., -20., -30.]);
+    }
+}
@@ -0,0 +1,138 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Permutation, TriangularSolveOptions, Vector};
+use crate::traits::{Abs, Field};
+
+/// Dense PA = LU factorization with partial pivoting. L has a unit diagonal and is packed
+/// below U in a single matrix.
+#[derive(Debug, Clone)]
+pub struct Lu<K: Field> {
+    lu: Matrix<K>,
+    permutation: Permutation,
+}
+
+impl<K: Field> Matrix<K> {
+    /// Returns `None` when the matrix is singular.
+    pub fn lu(&self) -> Option<Lu<K>> {
+        assert!(self.is_square(), "LU factorization requires a square matrix");
+        let size = self.shape().0;
+        let mut lu = self.clone();
+        let mut permutation = Permutation::identity(size);
+
+        for x in 0..size {
+            let pivot_row = (x..size).fold(x, |best, y| {
+                if Abs::abs(lu[(x, y)]) > Abs::abs(lu[(x, best)]) { y } else { best }
+            });
+            if lu[(x, pivot_row)] == K::zero() {
+                return None;
+            }
+
+            if pivot_row != x {
+                lu.swap_rows(x, pivot_row);
+                permutation.swap(x, pivot_row);
+            }
+
+            for row in (x + 1)..size {
+                let mult = lu[(x, row)] / lu[(x, x)];
+                lu[(x, row)] = mult;
+                for col in (x + 1)..size {
+                    lu[(col, row)] = lu[(col, row)] - lu[(col, x)] * mult;
+                }
+            }
+        }
+
+        Some(Lu { lu, permutation })
+    }
+}
+
+impl<K: Field> Lu<K> {
+    pub fn size(&self) -> usize {
+        self.permutation.size()
+    }
+
+    /// P, with `P.apply_to_rows(A) = LU`.
+    pub fn permutation(&self) -> &Permutation {
+        &self.permutation
+    }
+
+    /// The unit lower triangular factor.
+    pub fn lower(&self) -> Matrix<K> {
+        let mut lower = self.lu.lower_triangular(-1);
+        lower.set_diagonal(0, &Vector::from_elem(K::one(), self.size()));
+        lower
+    }
+
+    pub fn upper(&self) -> Matrix<K> {
+        self.lu.upper_triangular(0)
+    }
+
+    /// Solves `A x = b`.
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        assert_eq!(b.size(), self.size(), "Vector size must equal matrix size");
+        let permuted = &self.permutation * b;
+        let unit = TriangularSolveOptions { unit_diagonal: true, ..Default::default() };
+
+        let y = self.lu.solve_lower_triangular(&permuted, unit).unwrap();
+        self.lu.solve_upper_triangular(&y, TriangularSolveOptions::default()).expect("LU pivots are non-zero")
+    }
+
+    /// Solves `Aᵀ x = b`.
+    pub fn solve_transpose(&self, b: &Vector<K>) -> Vector<K> {
+        assert_eq!(b.size(), self.size(), "Vector size must equal matrix size");
+        let transpose = TriangularSolveOptions { transpose: true, ..Default::default() };
+
+        let w = self.lu.solve_upper_triangular(b, transpose).expect("LU pivots are non-zero");
+        let w = self.lu.solve_lower_triangular(&w, TriangularSolveOptions { unit_diagonal: true, ..transpose }).unwrap();
+        &self.permutation.inverse() * &w
+    }
+
+    pub fn determinant(&self) -> K {
+        (0..self.size()).fold(self.permutation.sign(), |acc, i| acc * self.lu[(i, i)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lu_factors() {
+        let a = Matrix::from_rows([
+            [1., 2., 0.],
+            [4., 1., 3.],
+            [-2., 5., 1.],
+        ]);
+        let lu = a.lu().unwrap();
+        assert_eq!(lu.permutation().indices(), [1, 2, 0]);
+        assert!((lu.determinant() - a.determinant()).abs() < 1e-12);
+        assert!(lu.lower().is_lower_triangular(0.));
+        assert_eq!(lu.lower().diagonal(0), Vector::from([1., 1., 1.]));
+        assert!(lu.upper().is_upper_triangular(0.));
+
+        let (lower, upper) = (lu.lower(), lu.upper());
+        let product = Matrix::from_fn(3, 3, |x, y| (0..3).fold(0., |acc, i| acc + lower[(i, y)] * upper[(x, i)]));
+        assert!((product - lu.permutation().apply_to_rows(&a)).norm_max() < 1e-12);
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let a = Matrix::from_rows([
+            [1., 2., 0.],
+            [4., 1., 3.],
+            [-2., 5., 1.],
+        ]);
+        let x = Vector::from([1., -2., 3.]);
+        let lu = a.lu().unwrap();
+        assert!((lu.solve(&(&a * &x)) - x.clone()).norm() < 1e-12);
+        assert!((lu.solve_transpose(&(&a.transpose() * &x)) - x).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let a = Matrix::from_rows([
+            [1., 2.],
+            [2., 4.],
+        ]);
+        assert!(a.lu().is_none());
+    }
+}
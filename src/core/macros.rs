@@ -0,0 +1,48 @@
+/// Builds a `Matrix` row by row: `matrix![1, 2, 3; 4, 5, 6]` is a 2x3 matrix.
+///
+/// Expands to `Matrix::from_rows`, so rows of different lengths fail to compile.
+#[macro_export]
+macro_rules! matrix {
+    ($($($x:expr),+ $(,)?);+ $(;)?) => {
+        $crate::core::Matrix::from_rows([$([$($x),+]),+])
+    };
+}
+
+/// Builds a `Vector` from its elements: `vector![1., 2., 3.]`.
+#[macro_export]
+macro_rules! vector {
+    ($($x:expr),+ $(,)?) => {
+        $crate::core::Vector::from([$($x),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{Matrix, Vector};
+
+    #[test]
+    fn test_matrix_macro() {
+        let a = matrix![1, 2, 3; 4, 5, 6];
+        assert_eq!(a.shape(), (3, 2));
+        assert_eq!(a, Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]));
+        assert_eq!(a[(2, 0)], 3);
+
+        let b = matrix![
+            1., 2.,;
+            3., 4.,;
+        ];
+        assert_eq!(b, Matrix::from_columns([[1., 3.], [2., 4.]]));
+        assert_eq!(matrix![7], Matrix::from_elem(7, 1, 1));
+        assert_eq!(matrix![1 + 1, -3], Matrix::from_rows([[2, -3]]));
+    }
+
+    #[test]
+    fn test_vector_macro() {
+        let v: Vector<f64> = vector![1., 2., 3.];
+        assert_eq!(v, Vector::from([1., 2., 3.]));
+        assert_eq!(vector![4, 5,].size(), 2);
+    }
+}
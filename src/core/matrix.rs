@@ -1,8 +1,8 @@
 #![allow(dead_code)]
 
-use crate::core::Vector;
+use crate::core::{Permutation, Vector};
 use crate::traits::{Abs, Field, MulAdd};
-use std::{fmt, ops};
+use std::{error, fmt, ops};
 
 #[derive(Debug)]
 pub struct Matrix<K: Field> {
@@ -19,6 +19,19 @@ impl<K: Field> Matrix<K> {
         self.shape.0 == self.shape.1
     }
 
+    /// Elements in column-major storage order.
+    pub fn as_slice(&self) -> &[K] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [K] {
+        &mut self.data
+    }
+
+    pub fn into_vec(self) -> Vec<K> {
+        self.data
+    }
+
     pub fn from_rows<const W: usize, const H: usize>(values: [[K; W]; H]) -> Self {
         let mut data = Vec::<K>::with_capacity(W * H);
 
@@ -66,6 +79,47 @@ impl<K: Field> Matrix<K> {
         mat
     }
 
+    /// Builds a `width`x`height` matrix whose element at `(x, y)` is `f(x, y)`.
+    pub fn from_fn<F: FnMut(usize, usize) -> K>(width: usize, height: usize, mut f: F) -> Self {
+        let mut data = Vec::<K>::with_capacity(width * height);
+
+        for x in 0..width {
+            for y in 0..height {
+                data.push(f(x, y));
+            }
+        }
+
+        Matrix {
+            shape: (width, height),
+            data,
+        }
+    }
+
+    pub fn from_row_major_vec(width: usize, height: usize, values: Vec<K>) -> Self {
+        assert_eq!(values.len(), width * height, "Vector length must equal width * height");
+        Matrix::from_fn(width, height, |x, y| values[y * width + x])
+    }
+
+    pub fn from_column_major_vec(width: usize, height: usize, values: Vec<K>) -> Self {
+        assert_eq!(values.len(), width * height, "Vector length must equal width * height");
+
+        Matrix {
+            shape: (width, height),
+            data: values,
+        }
+    }
+
+    pub fn from_diagonal(diagonal: &Vector<K>) -> Self {
+        let size = diagonal.size();
+        let mut mat = Matrix::from_elem(K::zero(), size, size);
+
+        for i in 0..size {
+            mat[(i, i)] = diagonal[i];
+        }
+
+        mat
+    }
+
     pub fn trace(&self) -> K {
         assert!(self.is_square(), "Trace is only defined for square matrices");
         let mut result = K::zero();
@@ -103,11 +157,7 @@ impl<K: Field> Matrix<K> {
 
             // Swap current row with pivot row
             if pivot_row != y {
-                for i in 0..cols {
-                    let temp = result[(i, y)];
-                    result[(i, y)] = result[(i, pivot_row)];
-                    result[(i, pivot_row)] = temp;
-                }
+                result.swap_rows(y, pivot_row);
             }
 
             // Normalize pivot (left-most number should be one)
@@ -141,6 +191,7 @@ impl<K: Field> Matrix<K> {
             size if size <= 4 => {
                 let mut det_matrix = self.clone();
                 let (cols, rows) = det_matrix.shape;
+                let mut pivots = Permutation::identity(rows);
                 let mut y: usize = 0;
 
                 for x in 0..cols {
@@ -150,13 +201,9 @@ impl<K: Field> Matrix<K> {
                     }
                     let pivot_row = pivot_row.unwrap();
 
-                    // R_y <=> -R_pr
                     if pivot_row != y {
-                        for i in 0..cols {
-                            let temp = -det_matrix[(i, y)];
-                            det_matrix[(i, y)] = det_matrix[(i, pivot_row)];
-                            det_matrix[(i, pivot_row)] = temp;
-                        }
+                        det_matrix.swap_rows(y, pivot_row);
+                        pivots.swap(y, pivot_row);
                     }
 
                     for row in (y + 1)..rows {
@@ -170,7 +217,7 @@ impl<K: Field> Matrix<K> {
                     y += 1;
                 }
 
-                (0..rows).fold(K::one(), |acc, i| acc * det_matrix[(i, i)])
+                (0..rows).fold(pivots.sign(), |acc, i| acc * det_matrix[(i, i)])
             },
             _ => K::zero(),
         }
@@ -180,23 +227,9 @@ impl<K: Field> Matrix<K> {
         assert_eq!(self.shape.0, self.shape.1, "Inverse matrix is only defined for square matrices");
         assert_ne!(self.determinant(), K::zero(), "Matrix is not invertible");
 
-        let (cols, rows) = (self.shape.0, self.shape.1);
-        let mut inv_calc = Matrix::from_elem(K::zero(), cols * 2, rows);
-
-        for y in 0..rows {
-            for x in 0..cols {
-                inv_calc[(x, y)] = self[(x, y)];
-            }
-            inv_calc[(cols + y, y)] = K::one();
-        }
-        inv_calc = inv_calc.row_echelon();
-
-        let mut result = self.clone();
-        for y in 0..rows {
-            for x in 0..cols {
-                result[(x, y)] = inv_calc[(cols + x, y)];
-            }
-        }
+        // Reduce [A | I] to [I | A⁻¹]
+        let inv_calc = Matrix::hstack(&[self, &Matrix::identity(self.shape.0)]).row_echelon();
+        let (_, result) = inv_calc.split_at_column(self.shape.0);
 
         result
     }
@@ -212,38 +245,143 @@ impl<K: Field> Clone for Matrix<K>
     }
 }
 
+impl<K: Field> Default for Matrix<K> {
+    /// The empty 0x0 matrix.
+    fn default() -> Self {
+        Matrix::from_elem(K::zero(), 0, 0)
+    }
+}
+
+/// Returned when building a matrix from rows of different lengths; `row` is 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaggedRowsError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl fmt::Display for RaggedRowsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {}: expected {} elements, found {}", self.row, self.expected, self.found)
+    }
+}
+
+impl error::Error for RaggedRowsError {}
+
+impl<K: Field> TryFrom<Vec<Vec<K>>> for Matrix<K> {
+    type Error = RaggedRowsError;
+
+    fn try_from(rows: Vec<Vec<K>>) -> Result<Self, Self::Error> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(RaggedRowsError { row: y + 1, expected: width, found: row.len() });
+        }
+
+        Ok(Matrix::from_fn(width, rows.len(), |x, y| rows[y][x]))
+    }
+}
+
+/// Collects columns into a matrix.
+impl<K: Field> FromIterator<Vector<K>> for Matrix<K> {
+    fn from_iter<I: IntoIterator<Item = Vector<K>>>(iter: I) -> Self {
+        let mut data = Vec::new();
+        let mut width = 0;
+        let mut height = None;
+
+        for column in iter {
+            let size = *height.get_or_insert(column.size());
+            assert_eq!(column.size(), size, "All columns must have the same size");
+            data.extend((0..size).map(|i| column[i]));
+            width += 1;
+        }
+
+        Matrix {
+            shape: (width, height.unwrap_or(0)),
+            data,
+        }
+    }
+}
+
+/// Matrices larger than this are shown with their middle rows or columns elided.
+const DISPLAY_MAX_ROWS: usize = 12;
+const DISPLAY_MAX_COLUMNS: usize = 12;
+/// Rows or columns kept on each side of the elision.
+const DISPLAY_EDGE: usize = 5;
+
+/// Indices to display along one axis, `None` standing for the elided middle.
+fn displayed_indices(len: usize, max: usize) -> Vec<Option<usize>> {
+    if len <= max {
+        return (0..len).map(Some).collect();
+    }
+
+    (0..DISPLAY_EDGE)
+        .map(Some)
+        .chain([None])
+        .chain((len - DISPLAY_EDGE..len).map(Some))
+        .collect()
+}
+
+/// Honors the formatter's precision, width and alignment. `{:#}` draws ASCII brackets.
 impl<K: Field> fmt::Display for Matrix<K>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (width, height) = self.shape;
+        if width == 0 || height == 0 {
+            return write!(f, "[]");
+        }
 
-        let mut col_widths = vec![0; width];
-        for col in 0..width {
-            for row in 0..height {
-                let index = col * height + row;
-                let length = self.data[index].to_string().len();
-                col_widths[col] = col_widths[col].max(length);
+        let ascii = f.alternate();
+        let (row_ellipsis, col_ellipsis, diag_ellipsis) = if ascii { (":", "...", "") } else { ("⋮", "⋯", "⋱") };
+        let rows = displayed_indices(height, DISPLAY_MAX_ROWS);
+        let cols = displayed_indices(width, DISPLAY_MAX_COLUMNS);
+
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                cols.iter()
+                    .map(|col| match (row, col) {
+                        (Some(y), Some(x)) => match f.precision() {
+                            Some(precision) => format!("{:.precision$}", self[(*x, *y)]),
+                            None => self[(*x, *y)].to_string(),
+                        },
+                        (Some(_), None) => col_ellipsis.to_string(),
+                        (None, Some(_)) => row_ellipsis.to_string(),
+                        (None, None) => diag_ellipsis.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut col_widths = vec![f.width().unwrap_or(0); cols.len()];
+        for row in cells.iter() {
+            for (col, cell) in row.iter().enumerate() {
+                col_widths[col] = col_widths[col].max(cell.chars().count());
             }
         }
 
-        for row in 0..height {
+        for (row, line) in cells.iter().enumerate() {
             let delim_chars = match row {
+                _ if ascii || cells.len() == 1 => ('[', ']'),
                 0 => ('⎡', '⎤'),
-                r if r == height - 1 => ('⎣', '⎦'),
-                _ => ('⎢', '⎢'),
+                r if r == cells.len() - 1 => ('⎣', '⎦'),
+                _ => ('⎢', '⎥'),
             };
 
             write!(f, "{} ", delim_chars.0)?;
-            for col in 0..width {
-                let index = col * height + row;
-                let value = self.data[index].to_string();
-                write!(f, "{:>width$}", value, width = col_widths[col])?;
-                if col < width - 1 {
+            for (col, cell) in line.iter().enumerate() {
+                let padding = col_widths[col] - cell.chars().count();
+                let (left, right) = match f.align() {
+                    Some(fmt::Alignment::Left) => (0, padding),
+                    Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+                    _ => (padding, 0),
+                };
+                write!(f, "{:left$}{cell}{:right$}", "", "")?;
+                if col < line.len() - 1 {
                     write!(f, "  ")?;
                 }
             }
             write!(f, " {}", delim_chars.1)?;
-            if row < height - 1 {
+            if row < cells.len() - 1 {
                 writeln!(f)?;
             }
         }
@@ -367,6 +505,23 @@ impl<K: Field> ops::Mul<Vector<K>> for Matrix<K> {
     }
 }
 
+impl<K: Field> ops::Mul<&Vector<K>> for &Matrix<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        assert_eq!(rhs.size(), self.shape().0, "Vector size must equal matrix width");
+        let mut result = Vector::from_elem(K::zero(), self.shape().1);
+
+        for row in 0..self.shape().1 {
+            for i in 0..rhs.size() {
+                result[row] = MulAdd::mul_add(self[(i, row)], rhs[i], result[row]);
+            }
+        }
+
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,6 +547,57 @@ mod tests {
         assert_eq!(mat.shape, (2, 5));
     }
 
+    #[test]
+    fn test_matrix_from_fn() {
+        let mat = Matrix::from_fn(3, 2, |x, y| (10 * y + x) as i32);
+        assert_eq!(mat, Matrix::from_rows([
+            [0, 1, 2],
+            [10, 11, 12],
+        ]));
+        assert_eq!(Matrix::<f64>::from_fn(0, 0, |_, _| 1.).shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_matrix_from_vec() {
+        let expected = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        assert_eq!(Matrix::from_row_major_vec(3, 2, vec![1., 2., 3., 4., 5., 6.]), expected);
+        assert_eq!(Matrix::from_column_major_vec(3, 2, vec![1., 4., 2., 5., 3., 6.]), expected);
+
+        assert_eq!(Matrix::try_from(vec![vec![1., 2., 3.], vec![4., 5., 6.]]), Ok(expected));
+        assert_eq!(
+            Matrix::try_from(vec![vec![1, 2], vec![3, 4], vec![5]]),
+            Err(RaggedRowsError { row: 3, expected: 2, found: 1 })
+        );
+        assert_eq!(Matrix::<i32>::try_from(Vec::new()).map(|m| m.shape()), Ok((0, 0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_from_vec_length_mismatch() {
+        Matrix::from_row_major_vec(2, 2, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_matrix_from_iterator() {
+        let mat: Matrix<i32> = (1..=3).map(|x| Vector::from([x, 10 * x])).collect();
+        assert_eq!(mat, Matrix::from_rows([
+            [1, 2, 3],
+            [10, 20, 30],
+        ]));
+    }
+
+    #[test]
+    fn test_matrix_from_diagonal() {
+        assert_eq!(Matrix::from_diagonal(&Vector::from([1., 2., 3.])), Matrix::from_rows([
+            [1., 0., 0.],
+            [0., 2., 0.],
+            [0., 0., 3.],
+        ]));
+    }
+
     #[test]
     fn test_matrix_index() {
         let mat = Matrix::from_rows([
@@ -549,6 +755,7 @@ mod tests {
             [11., 9., 6.]
         ]);
         let u = Vector::from([100., 80., 60.]);
+        assert_eq!(&a * &u, Vector::from([1000., 1820., 1180., 2180.]));
         assert_eq!(a * u, Vector::from([1000., 1820., 1180., 2180.]));
     }
 
@@ -631,6 +838,36 @@ mod tests {
         assert_eq!(u.trace(), -21.0);
     }
 
+    #[test]
+    fn test_matrix_display() {
+        let mat = Matrix::from_rows([
+            [1., -2.5, 3.],
+            [4., 5., 6.126],
+            [7., 8., 9.],
+        ]);
+        assert_eq!(mat.to_string(), "⎡ 1  -2.5      3 ⎤\n⎢ 4     5  6.126 ⎥\n⎣ 7     8      9 ⎦");
+        assert_eq!(format!("{mat:.2}"), "⎡ 1.00  -2.50  3.00 ⎤\n⎢ 4.00   5.00  6.13 ⎥\n⎣ 7.00   8.00  9.00 ⎦");
+        assert_eq!(format!("{mat:#.1}"), "[ 1.0  -2.5  3.0 ]\n[ 4.0   5.0  6.1 ]\n[ 7.0   8.0  9.0 ]");
+        assert_eq!(format!("{:<3}", Matrix::from_rows([[1, 22]])), "[ 1    22  ]");
+        assert_eq!(format!("{:^5}", Matrix::from_rows([[1, 22]])), "[   1     22   ]");
+        assert_eq!(Matrix::<f32>::from_elem(0., 0, 3).to_string(), "[]");
+    }
+
+    #[test]
+    fn test_matrix_display_elided() {
+        let mat = Matrix::from_fn(20, 30, |x, y| ((x + y) % 10) as i32);
+        let text = mat.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2 * DISPLAY_EDGE + 1);
+        assert_eq!(lines[0], "⎡ 0  1  2  3  4  ⋯  5  6  7  8  9 ⎤");
+        assert_eq!(lines[DISPLAY_EDGE], "⎢ ⋮  ⋮  ⋮  ⋮  ⋮  ⋱  ⋮  ⋮  ⋮  ⋮  ⋮ ⎥");
+        assert_eq!(lines[2 * DISPLAY_EDGE], "⎣ 9  0  1  2  3  ⋯  4  5  6  7  8 ⎦");
+
+        let ascii = format!("{mat:#}");
+        assert_eq!(ascii.lines().nth(DISPLAY_EDGE), Some("[ :  :  :  :  :       :  :  :  :  : ]"));
+        assert!(ascii.lines().next().unwrap().contains(" ... "));
+    }
+
     #[test]
     fn test_matrix_transpose() {
         let u = Matrix::from_rows([[1., 2.]]);
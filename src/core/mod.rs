@@ -1,6 +1,29 @@
+#[macro_use]
+mod macros;
 mod matrix;
 mod vector;
+mod axis;
+mod broadcast;
+mod edit;
+mod elementwise;
+mod lu;
+mod norm;
+mod parse;
+mod permutation;
+mod solve;
+mod stack;
+mod structure;
 pub mod ops;
 
+#[allow(unused_imports)]
+pub use axis::*;
+#[allow(unused_imports)]
+pub use broadcast::*;
+#[allow(unused_imports)]
+pub use lu::*;
 pub use matrix::*;
+#[allow(unused_imports)]
+pub use parse::*;
+pub use permutation::*;
+pub use solve::*;
 pub use vector::*;
@@ -0,0 +1,358 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::{Abs, Field, MulAdd, Sqrt};
+
+const POWER_ITERATIONS: usize = 1000;
+const ESTIMATOR_ITERATIONS: usize = 5;
+
+impl<K: Field> Matrix<K> {
+    pub fn norm_frobenius(&self) -> K {
+        let (width, height) = self.shape();
+        let mut result = K::zero();
+
+        for x in 0..width {
+            for y in 0..height {
+                result = MulAdd::mul_add(self[(x, y)], self[(x, y)], result);
+            }
+        }
+
+        Sqrt::sqrt(result)
+    }
+
+    /// Induced 1-norm: the largest absolute column sum.
+    pub fn norm_1(&self) -> K {
+        let (width, height) = self.shape();
+        let mut result = K::zero();
+
+        for x in 0..width {
+            let sum = (0..height).fold(K::zero(), |acc, y| acc + Abs::abs(self[(x, y)]));
+            if sum > result {
+                result = sum;
+            }
+        }
+
+        result
+    }
+
+    /// Induced ∞-norm: the largest absolute row sum.
+    pub fn norm_inf(&self) -> K {
+        let (width, height) = self.shape();
+        let mut result = K::zero();
+
+        for y in 0..height {
+            let sum = (0..width).fold(K::zero(), |acc, x| acc + Abs::abs(self[(x, y)]));
+            if sum > result {
+                result = sum;
+            }
+        }
+
+        result
+    }
+
+    pub fn norm_max(&self) -> K {
+        let (width, height) = self.shape();
+        let mut result = K::zero();
+
+        for x in 0..width {
+            for y in 0..height {
+                let abs_value = Abs::abs(self[(x, y)]);
+                if abs_value > result {
+                    result = abs_value;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Spectral norm: the largest singular value, found by power iteration on AᵀA.
+    pub fn norm_2(&self) -> K {
+        let width = self.shape().0;
+        if width == 0 || self.norm_max() == K::zero() {
+            return K::zero();
+        }
+
+        let transpose = self.transpose();
+        let mut v = start_vector(width);
+        let eigenvalue = power_iteration(&mut v, |v| &transpose * &(self * v));
+        Sqrt::sqrt(eigenvalue)
+    }
+
+    /// 2-norm condition number σmax / σmin, or `None` when the matrix is singular.
+    /// An empty matrix has condition number zero, matching its zero norms.
+    pub fn condition_number(&self) -> Option<K> {
+        assert!(self.is_square(), "Condition number is only defined for square matrices");
+        let size = self.shape().0;
+        if size == 0 {
+            return Some(K::zero());
+        }
+        let lu = self.lu()?;
+
+        // Inverse iteration on AᵀA converges to 1 / σmin²
+        let mut v = start_vector(size);
+        let inverse_eigenvalue = power_iteration(&mut v, |v| lu.solve(&lu.solve_transpose(v)));
+
+        let norm = self.norm_2();
+        Some(norm * Sqrt::sqrt(inverse_eigenvalue))
+    }
+
+    /// Estimate of the 1-norm condition number ‖A‖₁‖A⁻¹‖₁ that never forms A⁻¹,
+    /// following Hager and Higham's estimator (LAPACK `xLACON`).
+    /// Returns `None` when the matrix is singular, and zero for an empty matrix.
+    pub fn condition_estimate(&self) -> Option<K> {
+        assert!(self.is_square(), "Condition number is only defined for square matrices");
+        let size = self.shape().0;
+        if size == 0 {
+            return Some(K::zero());
+        }
+        let lu = self.lu()?;
+
+        let n = (0..size).fold(K::zero(), |acc, _| acc + K::one());
+        let mut x = Vector::from_elem(K::one() / n, size);
+        let mut estimate = K::zero();
+
+        for iteration in 0..ESTIMATOR_ITERATIONS {
+            let y = lu.solve(&x);
+            let y_norm = y.norm_1();
+            if iteration > 0 && y_norm <= estimate {
+                break;
+            }
+            estimate = y_norm;
+
+            let mut signs = Vector::from_elem(K::one(), size);
+            for i in 0..size {
+                if y[i] < K::zero() {
+                    signs[i] = -K::one();
+                }
+            }
+
+            let z = lu.solve_transpose(&signs);
+            let mut j = 0;
+            for i in 1..size {
+                if Abs::abs(z[i]) > Abs::abs(z[j]) {
+                    j = i;
+                }
+            }
+            if iteration > 0 && Abs::abs(z[j]) <= z.dot(&x) {
+                break;
+            }
+
+            x = Vector::from_elem(K::zero(), size);
+            x[j] = K::one();
+        }
+
+        // Alternative estimate guarding against matrices that fool the gradient steps
+        if size > 1 {
+            let mut alternating = Vector::from_elem(K::zero(), size);
+            let mut step = K::zero();
+            let last = n - K::one();
+            for i in 0..size {
+                let value = K::one() + step / last;
+                alternating[i] = if i % 2 == 0 { value } else { -value };
+                step = step + K::one();
+            }
+
+            let three = K::one() + K::one() + K::one();
+            let alternative = (K::one() + K::one()) * lu.solve(&alternating).norm_1() / (three * n);
+            if alternative > estimate {
+                estimate = alternative;
+            }
+        }
+
+        Some(self.norm_1() * estimate)
+    }
+}
+
+/// A fixed pseudo-random start vector with entries of magnitude in [1, 2) and mixed signs.
+/// Unlike a basis or all-ones vector it is not an eigenvector of structured matrices, so
+/// power iteration does not get stuck on a non-dominant eigenvalue.
+fn start_vector<K: Field>(size: usize) -> Vector<K> {
+    let half = K::one() / (K::one() + K::one());
+    let mut state: u32 = 0x9e37_79b9;
+
+    Vector::from_fn(size, |_| {
+        // xorshift32
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+
+        let mut value = K::one();
+        let mut weight = half;
+        for bit in 0..8 {
+            if state >> bit & 1 == 1 {
+                value = value + weight;
+            }
+            weight = weight * half;
+        }
+        if state >> 31 == 1 { -value } else { value }
+    })
+}
+
+/// Relative change at which power iteration stops: a few hundred machine epsilons, found
+/// by halving (one for integer types).
+fn tolerance<K: Field>() -> K {
+    let two = K::one() + K::one();
+    let mut epsilon = K::one();
+    while K::one() + epsilon / two != K::one() {
+        epsilon = epsilon / two;
+    }
+
+    (0..8).fold(epsilon, |acc, _| acc * two)
+}
+
+/// Runs power iteration from `v` and returns the dominant eigenvalue magnitude of a
+/// positive semi-definite operator. Stops once the estimate changes by less than
+/// `tolerance()` relative to its value.
+fn power_iteration<K: Field, F: Fn(&Vector<K>) -> Vector<K>>(v: &mut Vector<K>, apply: F) -> K {
+    let tolerance: K = tolerance();
+    let mut eigenvalue = K::zero();
+    *v = v.clone() * (K::one() / v.norm());
+
+    for _ in 0..POWER_ITERATIONS {
+        let w = apply(v);
+        let norm = w.norm();
+        if norm == K::zero() {
+            return K::zero();
+        }
+        *v = w * (K::one() / norm);
+        let converged = Abs::abs(norm - eigenvalue) <= tolerance * norm;
+        eigenvalue = norm;
+        if converged {
+            break;
+        }
+    }
+
+    eigenvalue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_matrix_norms() {
+        let u = Matrix::from_rows([
+            [1., -2.],
+            [-3., 4.],
+        ]);
+        assert_close(u.norm_frobenius(), 30f64.sqrt());
+        assert_eq!(u.norm_1(), 6.);
+        assert_eq!(u.norm_inf(), 7.);
+        assert_eq!(u.norm_max(), 4.);
+        assert_close(u.norm_2(), (15. + 221f64.sqrt()).sqrt());
+
+        let u = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        assert_eq!(u.norm_1(), 9.);
+        assert_eq!(u.norm_inf(), 15.);
+        assert_close(u.norm_2(), u.transpose().norm_2());
+
+        let u: Matrix<f64> = Matrix::from_elem(0., 3, 3);
+        assert_eq!(u.norm_2(), 0.);
+    }
+
+    #[test]
+    fn test_matrix_norm_2_diagonal() {
+        let u = Matrix::from_rows([
+            [2., 0., 0.],
+            [0., -7., 0.],
+            [0., 0., 3.],
+        ]);
+        assert_close(u.norm_2(), 7.);
+        assert_close(u.condition_number().unwrap(), 3.5);
+    }
+
+    #[test]
+    fn test_condition_number() {
+        let u = Matrix::from_rows([
+            [1., -2.],
+            [-3., 4.],
+        ]);
+        let expected = ((15. + 221f64.sqrt()) / (15. - 221f64.sqrt())).sqrt();
+        assert!((u.condition_number().unwrap() - expected).abs() < 1e-6);
+
+        let u: Matrix<f64> = Matrix::identity(4);
+        assert_close(u.condition_number().unwrap(), 1.);
+
+        let u = Matrix::from_rows([
+            [1., 2.],
+            [2., 4.],
+        ]);
+        assert_eq!(u.condition_number(), None);
+    }
+
+    #[test]
+    fn test_norm_2_non_dominant_start() {
+        // Each axis is an eigenvector of AᵀA, and the heaviest column is not the dominant one
+        let u = Matrix::from_rows([
+            [1., 0.9, 0.],
+            [0.9, 1., 0.],
+            [0., 0., 1.5],
+        ]);
+        assert_close(u.norm_2(), 1.9);
+    }
+
+    #[test]
+    fn test_condition_number_non_dominant_start() {
+        // The all-ones vector is an eigenvector of AᵀA for the largest singular value
+        let u = Matrix::from_rows([
+            [1., 0.9],
+            [0.9, 1.],
+        ]);
+        assert_close(u.condition_number().unwrap(), 19.);
+        assert_close(u.condition_estimate().unwrap(), 19.);
+    }
+
+    #[test]
+    fn test_condition_number_empty() {
+        let u: Matrix<f64> = Matrix::default();
+        assert_eq!(u.condition_number(), Some(0.));
+        assert_eq!(u.condition_estimate(), Some(0.));
+    }
+
+    #[test]
+    fn test_condition_estimate() {
+        let u = Matrix::from_rows([
+            [1., -2.],
+            [-3., 4.],
+        ]);
+        assert_close(u.condition_estimate().unwrap(), 21.);
+
+        let u = Matrix::from_rows([
+            [4., 1., 0., 0., 0.],
+            [1., 4., 1., 0., 0.],
+            [0., 1., 4., 1., 0.],
+            [0., 0., 1., 4., 1.],
+            [0., 0., 0., 1., 4.],
+        ]);
+        let estimate = u.condition_estimate().unwrap();
+        assert!(estimate <= u.norm_1() * inverse_norm_1(&u) + 1e-9);
+        assert!(estimate >= u.condition_number().unwrap() / 5.);
+
+        let u = Matrix::from_rows([
+            [1., 2., 3.],
+            [2., 4., 6.],
+            [0., 0., 1.],
+        ]);
+        assert_eq!(u.condition_estimate(), None);
+    }
+
+    fn inverse_norm_1(u: &Matrix<f64>) -> f64 {
+        let lu = u.lu().unwrap();
+        let size = u.shape().0;
+        (0..size)
+            .map(|x| {
+                let mut e = Vector::from_elem(0., size);
+                e[x] = 1.;
+                lu.solve(&e).norm_1()
+            })
+            .fold(0., f64::max)
+    }
+}
@@ -0,0 +1,245 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::Field;
+use std::str::FromStr;
+use std::{error, fmt};
+
+const DISPLAY_BRACKETS: [char; 6] = ['⎡', '⎤', '⎢', '⎥', '⎣', '⎦'];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseMatrixError {
+    Empty,
+    UnbalancedBrackets,
+    /// An element that does not parse as `K`; `row` and `column` are 1-based.
+    InvalidElement { row: usize, column: usize, value: String },
+    Ragged { row: usize, expected: usize, found: usize },
+    /// A `Vector` literal that is neither a single row nor a single column.
+    NotAVector { rows: usize, columns: usize },
+}
+
+impl fmt::Display for ParseMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseMatrixError::Empty => write!(f, "matrix literal has no elements"),
+            ParseMatrixError::UnbalancedBrackets => write!(f, "unbalanced brackets in matrix literal"),
+            ParseMatrixError::InvalidElement { row, column, value } => {
+                write!(f, "row {row}, column {column}: cannot parse {value:?}")
+            }
+            ParseMatrixError::Ragged { row, expected, found } => {
+                write!(f, "row {row}: expected {expected} elements, found {found}")
+            }
+            ParseMatrixError::NotAVector { rows, columns } => {
+                write!(f, "expected a single row or column, found {rows}x{columns}")
+            }
+        }
+    }
+}
+
+impl error::Error for ParseMatrixError {}
+
+fn elements(row: &str) -> Vec<&str> {
+    row.split(|c: char| c == ',' || c.is_whitespace()).filter(|element| !element.is_empty()).collect()
+}
+
+/// Rows of our own `Display` output, one per line between the bracket glyphs.
+fn display_rows(s: &str) -> Vec<Vec<&str>> {
+    s.lines()
+        .map(|line| line.trim().trim_matches(DISPLAY_BRACKETS.as_slice()))
+        .filter(|line| !line.trim().is_empty())
+        .map(elements)
+        .collect()
+}
+
+/// Rows of the ASCII `{:#}` output, one bracketed row per line.
+fn ascii_rows(s: &str) -> Option<Vec<Vec<&str>>> {
+    let lines: Vec<&str> = s.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+    if lines.len() < 2 {
+        return None;
+    }
+
+    lines
+        .iter()
+        .map(|line| {
+            let row = line.strip_prefix('[')?.strip_suffix(']')?;
+            (!row.contains(['[', ']'])).then(|| elements(row))
+        })
+        .collect()
+}
+
+/// Rows of `[[1, 2], [3, 4]]`, given the text between the outer brackets.
+fn nested_rows(mut s: &str) -> Result<Vec<Vec<&str>>, ParseMatrixError> {
+    let mut rows = Vec::new();
+
+    loop {
+        s = s.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        if s.is_empty() {
+            return Ok(rows);
+        }
+        let row = s.strip_prefix('[').ok_or(ParseMatrixError::UnbalancedBrackets)?;
+        let end = row.find(']').ok_or(ParseMatrixError::UnbalancedBrackets)?;
+        if row[..end].contains('[') {
+            return Err(ParseMatrixError::UnbalancedBrackets);
+        }
+        rows.push(elements(&row[..end]));
+        s = &row[end + 1..];
+    }
+}
+
+/// Rows of `[1 2 3; 4 5 6]`, given the text between the brackets. Newlines also separate rows.
+fn matlab_rows(s: &str) -> Result<Vec<Vec<&str>>, ParseMatrixError> {
+    if s.contains(['[', ']']) {
+        return Err(ParseMatrixError::UnbalancedBrackets);
+    }
+
+    Ok(s.split([';', '\n']).filter(|row| !row.trim().is_empty()).map(elements).collect())
+}
+
+fn parse_rows<K: Field + FromStr>(s: &str) -> Result<Matrix<K>, ParseMatrixError> {
+    let s = s.trim();
+    let rows = if s.contains(DISPLAY_BRACKETS) {
+        display_rows(s)
+    } else if let Some(rows) = ascii_rows(s) {
+        rows
+    } else if let Some(inner) = s.strip_prefix('[') {
+        let inner = inner.strip_suffix(']').ok_or(ParseMatrixError::UnbalancedBrackets)?.trim();
+        if inner.starts_with('[') {
+            nested_rows(inner)?
+        } else {
+            matlab_rows(inner)?
+        }
+    } else {
+        matlab_rows(s)?
+    };
+
+    let width = rows.first().map_or(0, Vec::len);
+    if width == 0 {
+        return Err(ParseMatrixError::Empty);
+    }
+    let mut result = Matrix::from_elem(K::zero(), width, rows.len());
+
+    for (y, row) in rows.iter().enumerate() {
+        if row.len() != width {
+            return Err(ParseMatrixError::Ragged { row: y + 1, expected: width, found: row.len() });
+        }
+        for (x, value) in row.iter().enumerate() {
+            result[(x, y)] = value.parse().map_err(|_| ParseMatrixError::InvalidElement {
+                row: y + 1,
+                column: x + 1,
+                value: value.to_string(),
+            })?;
+        }
+    }
+
+    Ok(result)
+}
+
+impl<K: Field + FromStr> FromStr for Matrix<K> {
+    type Err = ParseMatrixError;
+
+    /// Parses `[1 2 3; 4 5 6]`, `[[1, 2, 3], [4, 5, 6]]` or the (non-elided) output of `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_rows(s)
+    }
+}
+
+impl<K: Field + FromStr> FromStr for Vector<K> {
+    type Err = ParseMatrixError;
+
+    /// Accepts any matrix literal with a single row or a single column.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let matrix: Matrix<K> = parse_rows(s)?;
+        let (width, height) = matrix.shape();
+        if width != 1 && height != 1 {
+            return Err(ParseMatrixError::NotAVector { rows: height, columns: width });
+        }
+
+        let mut result = Vector::from_elem(K::zero(), width * height);
+        for i in 0..width * height {
+            result[i] = matrix[(i % width, i / width)];
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_matlab() {
+        let expected = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        assert_eq!("[1 2 3; 4 5 6]".parse::<Matrix<i32>>(), Ok(expected.clone()));
+        assert_eq!("[1, 2, 3;4,5,6;]".parse::<Matrix<i32>>(), Ok(expected.clone()));
+        assert_eq!("[1 2 3\n 4 5 6]".parse::<Matrix<i32>>(), Ok(expected.clone()));
+        assert_eq!("1 2 3; 4 5 6".parse::<Matrix<i32>>(), Ok(expected));
+        assert_eq!("[-1.5e2]".parse::<Matrix<f64>>(), Ok(Matrix::from_rows([[-150.]])));
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        let expected = Matrix::from_rows([
+            [1., 2.],
+            [3., 4.],
+        ]);
+        assert_eq!("[[1,2],[3,4]]".parse::<Matrix<f64>>(), Ok(expected.clone()));
+        assert_eq!(" [ [1., 2.], \n  [3., 4.] ] ".parse::<Matrix<f64>>(), Ok(expected));
+        assert_eq!("[[1, 2, 3]]".parse::<Matrix<f64>>(), Ok(Matrix::from_rows([[1., 2., 3.]])));
+    }
+
+    #[test]
+    fn test_parse_display_round_trip() {
+        let a = Matrix::from_rows([
+            [1.5, -2., 3.],
+            [4., 50., -6.25],
+            [7., 8., 9.],
+        ]);
+        assert_eq!(a.to_string().parse::<Matrix<f64>>(), Ok(a));
+
+        let b = Matrix::from_rows([[10, -200]]);
+        assert_eq!(b.to_string().parse::<Matrix<i64>>(), Ok(b.clone()));
+        assert_eq!(format!("{b:#}").parse::<Matrix<i64>>(), Ok(b));
+
+        let c = Matrix::from_rows([
+            [1, 2],
+            [3, 4],
+        ]);
+        assert_eq!(format!("{c:#}").parse::<Matrix<i64>>(), Ok(c));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "[1 2 3; 4 5]".parse::<Matrix<i32>>(),
+            Err(ParseMatrixError::Ragged { row: 2, expected: 3, found: 2 })
+        );
+        assert_eq!(
+            "[[1, 2], [3, 4], [5]]".parse::<Matrix<i32>>(),
+            Err(ParseMatrixError::Ragged { row: 3, expected: 2, found: 1 })
+        );
+        assert_eq!(
+            "[1 2; 3 x]".parse::<Matrix<i32>>(),
+            Err(ParseMatrixError::InvalidElement { row: 2, column: 2, value: "x".to_string() })
+        );
+        assert_eq!("[]".parse::<Matrix<i32>>(), Err(ParseMatrixError::Empty));
+        assert_eq!("[1 2".parse::<Matrix<i32>>(), Err(ParseMatrixError::UnbalancedBrackets));
+        assert_eq!("[[1, 2], 3]".parse::<Matrix<i32>>(), Err(ParseMatrixError::UnbalancedBrackets));
+        assert_eq!("[[1, [2]]]".parse::<Matrix<i32>>(), Err(ParseMatrixError::UnbalancedBrackets));
+    }
+
+    #[test]
+    fn test_parse_vector() {
+        let expected: Vector<f32> = Vector::from([1., 2., 3.]);
+        assert_eq!("[1 2 3]".parse::<Vector<f32>>(), Ok(expected.clone()));
+        assert_eq!("[1; 2; 3]".parse::<Vector<f32>>(), Ok(expected.clone()));
+        assert_eq!(expected.to_string().parse::<Vector<f32>>(), Ok(expected));
+        assert_eq!(
+            "[1 2; 3 4]".parse::<Vector<f32>>(),
+            Err(ParseMatrixError::NotAVector { rows: 2, columns: 2 })
+        );
+    }
+}
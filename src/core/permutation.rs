@@ -0,0 +1,178 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::Field;
+use std::ops;
+
+/// A permutation of `0..n`, stored as an index vector: applied to rows, row `i` of the
+/// result is row `indices[i]` of the input, like NumPy's `a[p]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl Permutation {
+    pub fn new(indices: Vec<usize>) -> Self {
+        let mut seen = vec![false; indices.len()];
+        for &i in &indices {
+            assert!(i < indices.len() && !seen[i], "Indices must be a permutation of 0..n");
+            seen[i] = true;
+        }
+        Permutation { indices }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        Permutation { indices: (0..size).collect() }
+    }
+
+    pub fn size(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Swaps entries `a` and `b`, i.e. swaps rows `a` and `b` of the permutation matrix.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.indices.swap(a, b);
+    }
+
+    /// The permutation whose matrix is `self.to_matrix() * other.to_matrix()`: `other` is
+    /// applied first.
+    pub fn compose(&self, other: &Permutation) -> Permutation {
+        assert_eq!(self.size(), other.size(), "Permutation sizes mismatch.");
+        Permutation { indices: self.indices.iter().map(|&i| other.indices[i]).collect() }
+    }
+
+    pub fn inverse(&self) -> Permutation {
+        let mut indices = vec![0; self.size()];
+        for (i, &p) in self.indices.iter().enumerate() {
+            indices[p] = i;
+        }
+        Permutation { indices }
+    }
+
+    /// Cycles of length two or more, each starting at its smallest element.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.size()];
+        let mut cycles = Vec::new();
+
+        for start in 0..self.size() {
+            if visited[start] || self.indices[start] == start {
+                continue;
+            }
+            let mut cycle = Vec::new();
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                cycle.push(i);
+                i = self.indices[i];
+            }
+            cycles.push(cycle);
+        }
+
+        cycles
+    }
+
+    /// Whether the permutation is a product of an even number of transpositions.
+    pub fn is_even(&self) -> bool {
+        self.cycles().iter().map(|cycle| cycle.len() - 1).sum::<usize>().is_multiple_of(2)
+    }
+
+    /// 1 for even permutations, -1 for odd ones; the determinant of the permutation matrix.
+    pub fn sign<K: Field>(&self) -> K {
+        if self.is_even() { K::one() } else { -K::one() }
+    }
+
+    /// `P * matrix`: row `i` of the result is row `indices[i]` of `matrix`.
+    pub fn apply_to_rows<K: Field>(&self, matrix: &Matrix<K>) -> Matrix<K> {
+        let (width, height) = matrix.shape();
+        assert_eq!(self.size(), height, "Permutation size must equal the number of rows");
+        Matrix::from_fn(width, height, |x, y| matrix[(x, self.indices[y])])
+    }
+
+    /// `matrix * Pᵀ`: column `i` of the result is column `indices[i]` of `matrix`.
+    pub fn apply_to_columns<K: Field>(&self, matrix: &Matrix<K>) -> Matrix<K> {
+        let (width, height) = matrix.shape();
+        assert_eq!(self.size(), width, "Permutation size must equal the number of columns");
+        Matrix::from_fn(width, height, |x, y| matrix[(self.indices[x], y)])
+    }
+
+    pub fn to_matrix<K: Field>(&self) -> Matrix<K> {
+        Matrix::from_fn(self.size(), self.size(), |x, y| if self.indices[y] == x { K::one() } else { K::zero() })
+    }
+}
+
+impl ops::Index<usize> for Permutation {
+    type Output = usize;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.indices[index]
+    }
+}
+
+impl<K: Field> ops::Mul<&Vector<K>> for &Permutation {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        assert_eq!(rhs.size(), self.size(), "Vector size must equal permutation size");
+        Vector::from_fn(self.size(), |i| rhs[self.indices[i]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let a = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+            [7, 8, 9],
+        ]);
+        let p = Permutation::new(vec![2, 0, 1]);
+        assert_eq!(p.apply_to_rows(&a), Matrix::from_rows([
+            [7, 8, 9],
+            [1, 2, 3],
+            [4, 5, 6],
+        ]));
+        assert_eq!(p.apply_to_rows(&a), p.to_matrix() * a.clone());
+        assert_eq!(p.apply_to_columns(&a), Matrix::from_rows([
+            [3, 1, 2],
+            [6, 4, 5],
+            [9, 7, 8],
+        ]));
+        assert_eq!(p.apply_to_columns(&a), a * p.to_matrix().transpose());
+        assert_eq!(&p * &Vector::from([10, 20, 30]), Vector::from([30, 10, 20]));
+    }
+
+    #[test]
+    fn test_compose_inverse() {
+        let p = Permutation::new(vec![2, 0, 1, 3]);
+        let q = Permutation::new(vec![1, 0, 3, 2]);
+        assert_eq!(p.compose(&q).to_matrix::<i32>(), p.to_matrix::<i32>() * q.to_matrix());
+        assert_eq!(p.compose(&p.inverse()), Permutation::identity(4));
+        assert_eq!(p.inverse().to_matrix::<i32>(), p.to_matrix::<i32>().transpose());
+    }
+
+    #[test]
+    fn test_cycles_sign() {
+        let p = Permutation::new(vec![2, 0, 1, 4, 3, 5]);
+        assert_eq!(p.cycles(), vec![vec![0, 2, 1], vec![3, 4]]);
+        assert_eq!(p.sign::<i32>(), -1);
+        assert_eq!(Permutation::identity(3).sign::<i32>(), 1);
+        assert!(Permutation::new(vec![1, 2, 0]).is_even());
+
+        for p in [Permutation::new(vec![1, 0, 2]), Permutation::new(vec![2, 0, 1])] {
+            assert_eq!(p.to_matrix::<f64>().determinant(), p.sign());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_not_a_permutation() {
+        Permutation::new(vec![0, 2, 2]);
+    }
+}
@@ -0,0 +1,157 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::Field;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TriangularSolveOptions {
+    /// Treats the diagonal as ones without reading it, as for the L of an LU factorization.
+    pub unit_diagonal: bool,
+    /// Solves with the transpose of the triangle instead.
+    pub transpose: bool,
+}
+
+impl<K: Field> Matrix<K> {
+    /// Solves `L x = b` by forward substitution, reading only the lower triangle of `self`.
+    /// Returns `None` when a diagonal element is zero.
+    pub fn solve_lower_triangular(&self, b: &Vector<K>, options: TriangularSolveOptions) -> Option<Vector<K>> {
+        assert_eq!(b.size(), self.shape().1, "Vector size must equal matrix size");
+        let mut x = b.clone();
+        self.substitute(true, x.as_mut_slice(), options).then_some(x)
+    }
+
+    /// Solves `U x = b` by back substitution, reading only the upper triangle of `self`.
+    /// Returns `None` when a diagonal element is zero.
+    pub fn solve_upper_triangular(&self, b: &Vector<K>, options: TriangularSolveOptions) -> Option<Vector<K>> {
+        assert_eq!(b.size(), self.shape().1, "Vector size must equal matrix size");
+        let mut x = b.clone();
+        self.substitute(false, x.as_mut_slice(), options).then_some(x)
+    }
+
+    /// Solves `L X = B` for every column of `B`.
+    pub fn solve_lower_triangular_matrix(&self, b: &Matrix<K>, options: TriangularSolveOptions) -> Option<Matrix<K>> {
+        self.substitute_columns(true, b, options)
+    }
+
+    /// Solves `U X = B` for every column of `B`.
+    pub fn solve_upper_triangular_matrix(&self, b: &Matrix<K>, options: TriangularSolveOptions) -> Option<Matrix<K>> {
+        self.substitute_columns(false, b, options)
+    }
+
+    fn substitute_columns(&self, lower: bool, b: &Matrix<K>, options: TriangularSolveOptions) -> Option<Matrix<K>> {
+        let size = self.shape().1;
+        assert_eq!(b.shape().1, size, "Right-hand side height must equal matrix size");
+        let mut x = b.clone();
+
+        if size > 0 {
+            for column in x.as_mut_slice().chunks_mut(size) {
+                if !self.substitute(lower, column, options) {
+                    return None;
+                }
+            }
+        }
+
+        Some(x)
+    }
+
+    /// Overwrites `x` with the solution, or returns false on a zero diagonal element.
+    fn substitute(&self, lower: bool, x: &mut [K], options: TriangularSolveOptions) -> bool {
+        assert!(self.is_square(), "Triangular solves require a square matrix");
+        let size = x.len();
+        let element = |x: usize, y: usize| if options.transpose { self[(y, x)] } else { self[(x, y)] };
+        if !options.unit_diagonal && (0..size).any(|i| self[(i, i)] == K::zero()) {
+            return false;
+        }
+
+        // The transpose of a lower triangle is upper, so it is solved backwards
+        let forward = lower != options.transpose;
+        let rows: Box<dyn Iterator<Item = usize>> = if forward { Box::new(0..size) } else { Box::new((0..size).rev()) };
+        for y in rows {
+            let known = if forward { 0..y } else { y + 1..size };
+            let value = known.fold(x[y], |acc, i| acc - element(i, y) * x[i]);
+            x[y] = if options.unit_diagonal { value } else { value / element(y, y) };
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_triangular() {
+        // Both triangles hold data, so each solve must ignore the other one
+        let a = Matrix::from_rows([
+            [2., 1., -1.],
+            [4., 3., 5.],
+            [-2., 6., 4.],
+        ]);
+        let x = Vector::from([1., -2., 3.]);
+        let lower = a.lower_triangular(0);
+        let upper = a.upper_triangular(0);
+        let options = TriangularSolveOptions::default();
+
+        assert_eq!(a.solve_lower_triangular(&(&lower * &x), options), Some(x.clone()));
+        assert_eq!(a.solve_upper_triangular(&(&upper * &x), options), Some(x.clone()));
+    }
+
+    #[test]
+    fn test_solve_triangular_options() {
+        let a = Matrix::from_rows([
+            [2., 1., -1.],
+            [4., 3., 5.],
+            [-2., 6., 4.],
+        ]);
+        let x = Vector::from([1., -2., 3.]);
+        let mut unit_lower = a.lower_triangular(-1);
+        unit_lower.set_diagonal(0, &Vector::from([1., 1., 1.]));
+        let upper = a.upper_triangular(0);
+
+        let unit = TriangularSolveOptions { unit_diagonal: true, ..Default::default() };
+        assert_eq!(a.solve_lower_triangular(&(&unit_lower * &x), unit), Some(x.clone()));
+
+        let transpose = TriangularSolveOptions { transpose: true, ..Default::default() };
+        let b = &upper.transpose() * &x;
+        assert_eq!(a.solve_upper_triangular(&b, transpose), Some(x.clone()));
+
+        let both = TriangularSolveOptions { unit_diagonal: true, transpose: true };
+        let b = &unit_lower.transpose() * &x;
+        assert_eq!(a.solve_lower_triangular(&b, both), Some(x));
+    }
+
+    #[test]
+    fn test_solve_triangular_matrix() {
+        let a = Matrix::from_rows([
+            [2., 1., -1.],
+            [4., 3., 5.],
+            [-2., 6., 4.],
+        ]);
+        let x = Matrix::from_rows([
+            [1., 0.],
+            [-2., 4.],
+            [3., -1.],
+        ]);
+        let lower = a.lower_triangular(0);
+        let b = Matrix::from_fn(2, 3, |col, y| (0..3).fold(0., |acc, i| acc + lower[(i, y)] * x[(col, i)]));
+        let options = TriangularSolveOptions::default();
+
+        assert_eq!(a.solve_lower_triangular_matrix(&b, options), Some(x));
+        assert_eq!(Matrix::<f64>::default().solve_upper_triangular_matrix(&Matrix::default(), options), Some(Matrix::default()));
+    }
+
+    #[test]
+    fn test_solve_triangular_singular() {
+        let singular = Matrix::from_rows([
+            [1., 0.],
+            [3., 0.],
+        ]);
+        let options = TriangularSolveOptions::default();
+        assert_eq!(singular.solve_lower_triangular(&Vector::from([1., 1.]), options), None);
+        assert_eq!(singular.solve_lower_triangular_matrix(&Matrix::identity(2), options), None);
+
+        let unit = TriangularSolveOptions { unit_diagonal: true, ..Default::default() };
+        assert_eq!(singular.solve_lower_triangular(&Vector::from([1., 1.]), unit), Some(Vector::from([1., -2.])));
+    }
+}
@@ -0,0 +1,150 @@
+#![allow(dead_code)]
+
+use crate::core::Matrix;
+use crate::traits::Field;
+
+impl<K: Field> Matrix<K> {
+    /// Places the matrices side by side. They must all have the same height.
+    pub fn hstack(matrices: &[&Matrix<K>]) -> Matrix<K> {
+        assert!(!matrices.is_empty(), "matrices array must not be empty");
+        let height = matrices[0].shape().1;
+        assert!(matrices.iter().all(|m| m.shape().1 == height), "matrices array must have same height");
+
+        // Column-major storage makes this a plain concatenation
+        let width = matrices.iter().map(|m| m.shape().0).sum();
+        let data = matrices.iter().flat_map(|m| m.as_slice().iter().copied()).collect();
+        Matrix::from_column_major_vec(width, height, data)
+    }
+
+    /// Places the matrices one above the other. They must all have the same width.
+    pub fn vstack(matrices: &[&Matrix<K>]) -> Matrix<K> {
+        assert!(!matrices.is_empty(), "matrices array must not be empty");
+        let width = matrices[0].shape().0;
+        assert!(matrices.iter().all(|m| m.shape().0 == width), "matrices array must have same width");
+
+        let height = matrices.iter().map(|m| m.shape().1).sum();
+        let data = (0..width)
+            .flat_map(|x| matrices.iter().flat_map(move |m| (0..m.shape().1).map(move |y| m[(x, y)])))
+            .collect();
+        Matrix::from_column_major_vec(width, height, data)
+    }
+
+    /// Assembles a matrix from a grid of blocks, given row by row. Blocks in the same
+    /// block row must share a height, and every block row must add up to the same width.
+    pub fn block(blocks: &[&[&Matrix<K>]]) -> Matrix<K> {
+        let rows: Vec<Matrix<K>> = blocks.iter().map(|row| Matrix::hstack(row)).collect();
+        Matrix::vstack(&rows.iter().collect::<Vec<_>>())
+    }
+
+    /// Splits into the columns before `x` and the columns from `x` on.
+    pub fn split_at_column(&self, x: usize) -> (Matrix<K>, Matrix<K>) {
+        let (width, height) = self.shape();
+        assert!(x <= width, "Split column out of bounds");
+
+        let (left, right) = self.as_slice().split_at(x * height);
+        (
+            Matrix::from_column_major_vec(x, height, left.to_vec()),
+            Matrix::from_column_major_vec(width - x, height, right.to_vec()),
+        )
+    }
+
+    /// Splits into the rows above `y` and the rows from `y` on.
+    pub fn split_at_row(&self, y: usize) -> (Matrix<K>, Matrix<K>) {
+        let (width, height) = self.shape();
+        assert!(y <= height, "Split row out of bounds");
+
+        (
+            Matrix::from_fn(width, y, |x, row| self[(x, row)]),
+            Matrix::from_fn(width, height - y, |x, row| self[(x, y + row)]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hstack() {
+        let a = Matrix::from_rows([
+            [1, 2],
+            [3, 4],
+        ]);
+        let b = Matrix::from_rows([
+            [5],
+            [6],
+        ]);
+        assert_eq!(Matrix::hstack(&[&a, &b, &a]), Matrix::from_rows([
+            [1, 2, 5, 1, 2],
+            [3, 4, 6, 3, 4],
+        ]));
+        assert_eq!(Matrix::hstack(&[&a]), a);
+    }
+
+    #[test]
+    fn test_vstack() {
+        let a = Matrix::from_rows([
+            [1, 2],
+            [3, 4],
+        ]);
+        let b = Matrix::from_rows([[5, 6]]);
+        assert_eq!(Matrix::vstack(&[&a, &b]), Matrix::from_rows([
+            [1, 2],
+            [3, 4],
+            [5, 6],
+        ]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hstack_height_mismatch() {
+        let a = Matrix::from_elem(0., 2, 2);
+        let b = Matrix::from_elem(0., 2, 3);
+        Matrix::hstack(&[&a, &b]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vstack_empty() {
+        Matrix::<f32>::vstack(&[]);
+    }
+
+    #[test]
+    fn test_block() {
+        // KKT layout [H Aᵀ; A 0]
+        let h = Matrix::from_rows([
+            [2., 0.],
+            [0., 2.],
+        ]);
+        let a = Matrix::from_rows([[1., 1.]]);
+        let kkt = Matrix::block(&[&[&h, &a.transpose()], &[&a, &Matrix::from_elem(0., 1, 1)]]);
+        assert_eq!(kkt, Matrix::from_rows([
+            [2., 0., 1.],
+            [0., 2., 1.],
+            [1., 1., 0.],
+        ]));
+    }
+
+    #[test]
+    fn test_split() {
+        let m = Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+            [7, 8, 9],
+        ]);
+
+        let (left, right) = m.split_at_column(1);
+        assert_eq!(left, Matrix::from_rows([[1], [4], [7]]));
+        assert_eq!(right, Matrix::from_rows([[2, 3], [5, 6], [8, 9]]));
+        assert_eq!(Matrix::hstack(&[&left, &right]), m);
+
+        let (top, bottom) = m.split_at_row(2);
+        assert_eq!(top, Matrix::from_rows([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(bottom, Matrix::from_rows([[7, 8, 9]]));
+        assert_eq!(Matrix::vstack(&[&top, &bottom]), m);
+
+        let (empty, all) = m.split_at_row(0);
+        assert_eq!((empty.shape(), all), ((3, 0), m.clone()));
+        assert_eq!(m.split_at_column(3).1.shape(), (0, 3));
+    }
+}
@@ -0,0 +1,218 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::{Abs, Field};
+
+/// Diagonal offsets follow NumPy: `k > 0` is above the main diagonal, `k < 0` below it,
+/// so element `(x, y)` lies on diagonal `x - y`.
+fn offset(x: usize, y: usize) -> isize {
+    x as isize - y as isize
+}
+
+impl<K: Field> Matrix<K> {
+    fn diagonal_positions(&self, k: isize) -> impl Iterator<Item = (usize, usize)> + use<K> {
+        let (width, height) = self.shape();
+        (0..height)
+            .filter_map(move |y| usize::try_from(y as isize + k).ok().map(|x| (x, y)))
+            .filter(move |&(x, _)| x < width)
+    }
+
+    /// The elements of diagonal `k`, top to bottom; `diagonal(0)` is the main diagonal.
+    pub fn diagonal(&self, k: isize) -> Vector<K> {
+        self.diagonal_positions(k).map(|index| self[index]).collect()
+    }
+
+    pub fn set_diagonal(&mut self, k: isize, values: &Vector<K>) {
+        let positions: Vec<(usize, usize)> = self.diagonal_positions(k).collect();
+        assert_eq!(values.size(), positions.len(), "Vector size must equal diagonal length");
+
+        for (i, index) in positions.into_iter().enumerate() {
+            self[index] = values[i];
+        }
+    }
+
+    /// Keeps the elements on or above diagonal `k` and zeroes the rest, like NumPy's `triu`.
+    pub fn upper_triangular(&self, k: isize) -> Matrix<K> {
+        self.keep(|d| d >= k)
+    }
+
+    /// Keeps the elements on or below diagonal `k` and zeroes the rest, like NumPy's `tril`.
+    pub fn lower_triangular(&self, k: isize) -> Matrix<K> {
+        self.keep(|d| d <= k)
+    }
+
+    /// Keeps `lower` subdiagonals, the main diagonal and `upper` superdiagonals.
+    pub fn band(&self, lower: usize, upper: usize) -> Matrix<K> {
+        self.keep(|d| -(lower as isize) <= d && d <= upper as isize)
+    }
+
+    fn keep<F: Fn(isize) -> bool>(&self, keep: F) -> Matrix<K> {
+        let (width, height) = self.shape();
+        Matrix::from_fn(width, height, |x, y| if keep(offset(x, y)) { self[(x, y)] } else { K::zero() })
+    }
+
+    /// Whether every element with `reject(offset)` is within `tolerance` of zero.
+    fn negligible_where<F: Fn(isize) -> bool>(&self, tolerance: K, reject: F) -> bool {
+        let (width, height) = self.shape();
+        (0..width).all(|x| (0..height).all(|y| !reject(offset(x, y)) || Abs::abs(self[(x, y)]) <= tolerance))
+    }
+
+    pub fn is_symmetric(&self, tolerance: K) -> bool {
+        let size = self.shape().0;
+        self.is_square() && (0..size).all(|x| (0..x).all(|y| Abs::abs(self[(x, y)] - self[(y, x)]) <= tolerance))
+    }
+
+    pub fn is_diagonal(&self, tolerance: K) -> bool {
+        self.negligible_where(tolerance, |d| d != 0)
+    }
+
+    pub fn is_upper_triangular(&self, tolerance: K) -> bool {
+        self.negligible_where(tolerance, |d| d < 0)
+    }
+
+    pub fn is_lower_triangular(&self, tolerance: K) -> bool {
+        self.negligible_where(tolerance, |d| d > 0)
+    }
+
+    /// Whether the columns are orthonormal, i.e. `AᵀA` is within `tolerance` of the identity.
+    pub fn is_orthogonal(&self, tolerance: K) -> bool {
+        let size = self.shape().0;
+        self.is_square()
+            && (0..size).all(|i| {
+                (0..=i).all(|j| {
+                    let expected = if i == j { K::one() } else { K::zero() };
+                    Abs::abs(self.column(i).dot(&self.column(j).to_vector()) - expected) <= tolerance
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagonal() {
+        let m = Matrix::from_rows([
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+        ]);
+        assert_eq!(m.diagonal(0), Vector::from([1, 6, 11]));
+        assert_eq!(m.diagonal(1), Vector::from([2, 7, 12]));
+        assert_eq!(m.diagonal(3), Vector::from([4]));
+        assert_eq!(m.diagonal(-1), Vector::from([5, 10]));
+        assert_eq!(m.diagonal(-3).size(), 0);
+        assert_eq!(m.diagonal(4).size(), 0);
+    }
+
+    #[test]
+    fn test_set_diagonal() {
+        let mut m = Matrix::from_elem(0, 3, 3);
+        m.set_diagonal(0, &Vector::from([1, 2, 3]));
+        m.set_diagonal(-2, &Vector::from([9]));
+        assert_eq!(m, Matrix::from_rows([
+            [1, 0, 0],
+            [0, 2, 0],
+            [9, 0, 3],
+        ]));
+        assert_eq!(Matrix::from_diagonal(&m.diagonal(0)), m.band(0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_diagonal_size_mismatch() {
+        let mut m = Matrix::from_rows([
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+        ]);
+        m.set_diagonal(1, &Vector::from([1, 2]));
+    }
+
+    #[test]
+    fn test_triangular() {
+        let m = Matrix::from_rows([
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+        ]);
+        assert_eq!(m.upper_triangular(0), Matrix::from_rows([
+            [1, 2, 3, 4],
+            [0, 6, 7, 8],
+            [0, 0, 11, 12],
+        ]));
+        assert_eq!(m.upper_triangular(2), Matrix::from_rows([
+            [0, 0, 3, 4],
+            [0, 0, 0, 8],
+            [0, 0, 0, 0],
+        ]));
+        assert_eq!(m.lower_triangular(-1), Matrix::from_rows([
+            [0, 0, 0, 0],
+            [5, 0, 0, 0],
+            [9, 10, 0, 0],
+        ]));
+        assert_eq!(m.lower_triangular(0) + m.upper_triangular(1), m);
+    }
+
+    #[test]
+    fn test_band() {
+        let m = Matrix::from_rows([
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+        ]);
+        assert_eq!(m.band(1, 0), Matrix::from_rows([
+            [1, 0, 0, 0],
+            [5, 6, 0, 0],
+            [0, 10, 11, 0],
+        ]));
+        assert_eq!(m.band(0, 1), Matrix::from_rows([
+            [1, 2, 0, 0],
+            [0, 6, 7, 0],
+            [0, 0, 11, 12],
+        ]));
+    }
+
+    #[test]
+    fn test_predicates() {
+        let m = Matrix::from_rows([
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+        ]);
+        assert!(m.upper_triangular(0).is_upper_triangular(0));
+        assert!(!m.is_upper_triangular(0));
+        assert!(m.lower_triangular(0).is_lower_triangular(0));
+        assert!(m.band(0, 0).is_diagonal(0));
+        assert!(!m.is_symmetric(0));
+
+        let s = Matrix::from_rows([
+            [2., 1.],
+            [1.0001, 3.],
+        ]);
+        assert!(!s.is_symmetric(0.));
+        assert!(s.is_symmetric(1e-3));
+        assert!(Matrix::from_rows([[1., 1e-12], [0., 1.]]).is_diagonal(1e-9));
+    }
+
+    #[test]
+    fn test_is_orthogonal() {
+        let (c, s) = (0.6, 0.8);
+        let rotation = Matrix::from_rows([
+            [c, -s],
+            [s, c],
+        ]);
+        assert!(rotation.is_orthogonal(1e-12));
+        assert!(Matrix::<f64>::identity(4).is_orthogonal(0.));
+        assert!(!(rotation * 2.).is_orthogonal(1e-12));
+        assert!(!Matrix::from_elem(0., 2, 3).is_orthogonal(1.));
+
+        let permutation = Matrix::from_rows([
+            [0, 1, 0],
+            [0, 0, 1],
+            [1, 0, 0],
+        ]);
+        assert!(permutation.is_orthogonal(0));
+    }
+}
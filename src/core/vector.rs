@@ -14,12 +14,27 @@ impl<K: Field> Vector<K> {
         self.data.shape().1
     }
 
+    pub fn as_slice(&self) -> &[K] {
+        self.data.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [K] {
+        self.data.as_mut_slice()
+    }
+
     pub fn from_elem(elem: K, size: usize) -> Self {
         Vector {
             data: Matrix::from_elem(elem, 1, size)
         }
     }
 
+    /// Builds a vector whose element at `i` is `f(i)`.
+    pub fn from_fn<F: FnMut(usize) -> K>(size: usize, mut f: F) -> Self {
+        Vector {
+            data: Matrix::from_fn(1, size, |_, y| f(y)),
+        }
+    }
+
     pub fn linear_combination(vectors: &[Vector<K>], coeffs: &[K]) -> Vector<K> {
         assert!(!vectors.is_empty(), "vectors array must not be empty");
         assert_eq!(vectors.len(), coeffs.len(), "vectors array and coeffs array must have the same length");
@@ -116,6 +131,20 @@ impl<K: Field, const N: usize> From<[K; N]> for Vector<K> {
     }
 }
 
+impl<K: Field> From<Vec<K>> for Vector<K> {
+    fn from(value: Vec<K>) -> Self {
+        Vector {
+            data: Matrix::from_column_major_vec(1, value.len(), value),
+        }
+    }
+}
+
+impl<K: Field> FromIterator<K> for Vector<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        Vector::from(iter.into_iter().collect::<Vec<K>>())
+    }
+}
+
 impl<K: Field> fmt::Display for Vector<K> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.data.fmt(f)?;
@@ -193,6 +222,13 @@ mod tests {
         assert_eq!(vec[2], 3.);
     }
 
+    #[test]
+    fn test_vector_from_vec() {
+        assert_eq!(Vector::from(vec![1., 2., 3.]), Vector::from([1., 2., 3.]));
+        assert_eq!(Vector::from_fn(4, |i| (i * i) as i32), Vector::from([0, 1, 4, 9]));
+        assert_eq!((1..=3).map(|i| i * 2).collect::<Vector<i64>>(), Vector::from([2, 4, 6]));
+    }
+
     #[test]
     fn test_vector_index_mut() {
         let mut vec = Vector::from([1., 2., 3.]);
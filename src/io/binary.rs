@@ -0,0 +1,347 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::io::crc32::Crc32;
+use crate::traits::{Field, LeBytes};
+use std::io::{self, Read, Write};
+use std::{error, fmt};
+
+// Layout, all little-endian:
+//   magic "MTRX" | version u16 | type tag u8 | rank u8 | width u64 | height u64
+//   column-major payload | CRC-32 of everything before it (u32)
+const MAGIC: &[u8; 4] = b"MTRX";
+const VERSION: u16 = 1;
+const HEADER_SIZE: usize = 24;
+const CHUNK_SIZE: usize = 8192;
+
+/// Element types with a tag in the binary format.
+pub trait BinaryElement: Field + LeBytes {
+    const TAG: u8;
+}
+
+macro_rules! impl_binary_element {
+    ($($t:ty => $tag:expr),*) => {
+        $(
+        impl BinaryElement for $t {
+            const TAG: u8 = $tag;
+        }
+        )*
+    };
+}
+
+impl_binary_element!(f32 => 1, f64 => 2, i8 => 3, i16 => 4, i32 => 5, i64 => 6, i128 => 7);
+
+fn type_name(tag: u8) -> &'static str {
+    match tag {
+        1 => "f32",
+        2 => "f64",
+        3 => "i8",
+        4 => "i16",
+        5 => "i32",
+        6 => "i64",
+        7 => "i128",
+        _ => "unknown",
+    }
+}
+
+#[derive(Debug)]
+pub enum BinaryError {
+    Io(io::Error),
+    InvalidMagic,
+    UnsupportedVersion(u16),
+    TypeMismatch { expected: &'static str, found: &'static str },
+    /// A vector (rank 1) was read as a `Matrix` (rank 2) or the other way round, or the rank
+    /// byte is corrupt.
+    RankMismatch { expected: u8, found: u8 },
+    /// A rank-1 stream with more than one column, or a shape that overflows memory.
+    InvalidShape { width: u64, height: u64 },
+    /// The stream ended before the payload and checksum were complete.
+    Truncated,
+    ChecksumMismatch { expected: u32, found: u32 },
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryError::Io(error) => write!(f, "I/O error: {error}"),
+            BinaryError::InvalidMagic => write!(f, "not a binary matrix stream"),
+            BinaryError::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {version} (expected {VERSION})")
+            }
+            BinaryError::TypeMismatch { expected, found } => write!(f, "expected {expected} elements, found {found}"),
+            BinaryError::RankMismatch { expected, found } => write!(f, "expected rank {expected}, found {found}"),
+            BinaryError::InvalidShape { width, height } => write!(f, "invalid shape {width}x{height}"),
+            BinaryError::Truncated => write!(f, "stream is truncated"),
+            BinaryError::ChecksumMismatch { expected, found } => {
+                write!(f, "checksum mismatch: expected {expected:08x}, found {found:08x}")
+            }
+        }
+    }
+}
+
+impl error::Error for BinaryError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            BinaryError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BinaryError {
+    fn from(value: io::Error) -> Self {
+        match value.kind() {
+            io::ErrorKind::UnexpectedEof => BinaryError::Truncated,
+            _ => BinaryError::Io(value),
+        }
+    }
+}
+
+/// Wraps a reader or writer and checksums every byte passing through.
+struct Checksummed<T> {
+    inner: T,
+    crc: Crc32,
+}
+
+impl<T> Checksummed<T> {
+    fn new(inner: T) -> Self {
+        Checksummed { inner, crc: Crc32::new() }
+    }
+}
+
+impl<W: Write> Checksummed<W> {
+    fn write_all(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.crc.update(bytes);
+        self.inner.write_all(bytes)
+    }
+}
+
+impl<R: Read> Checksummed<R> {
+    fn read_exact(&mut self, bytes: &mut [u8]) -> io::Result<()> {
+        self.inner.read_exact(bytes)?;
+        self.crc.update(bytes);
+        Ok(())
+    }
+}
+
+fn write_stream<K: BinaryElement, W: Write>(
+    writer: W,
+    rank: u8,
+    (width, height): (usize, usize),
+    data: impl Iterator<Item = K>,
+) -> io::Result<()> {
+    let mut writer = Checksummed::new(writer);
+
+    let mut header = Vec::with_capacity(HEADER_SIZE);
+    header.extend_from_slice(MAGIC);
+    header.extend_from_slice(&VERSION.to_le_bytes());
+    header.extend_from_slice(&[K::TAG, rank]);
+    header.extend_from_slice(&(width as u64).to_le_bytes());
+    header.extend_from_slice(&(height as u64).to_le_bytes());
+    writer.write_all(&header)?;
+
+    let mut buffer = Vec::with_capacity(CHUNK_SIZE);
+    for value in data {
+        value.write_le(&mut buffer);
+        if buffer.len() >= CHUNK_SIZE {
+            writer.write_all(&buffer)?;
+            buffer.clear();
+        }
+    }
+    writer.write_all(&buffer)?;
+
+    let crc = writer.crc.finish();
+    writer.inner.write_all(&crc.to_le_bytes())
+}
+
+/// Column-major elements with their shape as (width, height).
+fn read_stream<K: BinaryElement, R: Read>(reader: R, rank: u8) -> Result<((usize, usize), Vec<K>), BinaryError> {
+    let mut reader = Checksummed::new(reader);
+
+    let mut header = [0u8; HEADER_SIZE];
+    reader.read_exact(&mut header[..4]).map_err(|_| BinaryError::InvalidMagic)?;
+    if &header[..4] != MAGIC {
+        return Err(BinaryError::InvalidMagic);
+    }
+    reader.read_exact(&mut header[4..])?;
+
+    let version = u16::read_le(&header[4..]);
+    if version != VERSION {
+        return Err(BinaryError::UnsupportedVersion(version));
+    }
+    if header[6] != K::TAG {
+        return Err(BinaryError::TypeMismatch { expected: type_name(K::TAG), found: type_name(header[6]) });
+    }
+    if header[7] != rank {
+        return Err(BinaryError::RankMismatch { expected: rank, found: header[7] });
+    }
+    let (width, height) = (u64::read_le(&header[8..]), u64::read_le(&header[16..]));
+    let len = usize::try_from(width)
+        .ok()
+        .zip(usize::try_from(height).ok())
+        .and_then(|(width, height)| width.checked_mul(height))
+        .filter(|len| len.checked_mul(K::SIZE).is_some())
+        .filter(|_| rank != 1 || width == 1)
+        .ok_or(BinaryError::InvalidShape { width, height })?;
+
+    // The capacity grows with the bytes actually read, so a corrupt shape cannot force a huge allocation
+    let mut data = Vec::with_capacity(len.min(CHUNK_SIZE));
+    let mut buffer = vec![0u8; CHUNK_SIZE / K::SIZE * K::SIZE];
+    let mut remaining = len * K::SIZE;
+    while remaining > 0 {
+        let chunk = &mut buffer[..remaining.min(CHUNK_SIZE / K::SIZE * K::SIZE)];
+        reader.read_exact(chunk)?;
+        data.extend(chunk.chunks_exact(K::SIZE).map(K::read_le));
+        remaining -= chunk.len();
+    }
+
+    let expected = reader.crc.finish();
+    let mut crc = [0u8; 4];
+    reader.inner.read_exact(&mut crc)?;
+    let found = u32::from_le_bytes(crc);
+    if found != expected {
+        return Err(BinaryError::ChecksumMismatch { expected, found });
+    }
+
+    Ok(((width as usize, height as usize), data))
+}
+
+impl<K: BinaryElement> Matrix<K> {
+    /// Streams the matrix in the versioned binary format, payload in column-major order.
+    pub fn write_to<W: Write>(&self, writer: W) -> io::Result<()> {
+        let (width, height) = self.shape();
+        let data = (0..width).flat_map(|x| (0..height).map(move |y| (x, y))).map(|index| self[index]);
+        write_stream(writer, 2, (width, height), data)
+    }
+
+    /// Reads a matrix written by `Matrix::write_to`.
+    pub fn read_from<R: Read>(reader: R) -> Result<Matrix<K>, BinaryError> {
+        let ((width, height), data) = read_stream::<K, R>(reader, 2)?;
+        let mut result = Matrix::from_elem(K::zero(), width, height);
+
+        for (i, value) in data.into_iter().enumerate() {
+            result[(i / height, i % height)] = value;
+        }
+
+        Ok(result)
+    }
+}
+
+impl<K: BinaryElement> Vector<K> {
+    pub fn write_to<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_stream(writer, 1, (1, self.size()), (0..self.size()).map(|i| self[i]))
+    }
+
+    /// Reads a vector written by `Vector::write_to`.
+    pub fn read_from<R: Read>(reader: R) -> Result<Vector<K>, BinaryError> {
+        let ((_, height), data) = read_stream::<K, R>(reader, 1)?;
+        let mut result = Vector::from_elem(K::zero(), height);
+        for (i, value) in data.into_iter().enumerate() {
+            result[i] = value;
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let a = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let mut buffer = Vec::new();
+        a.write_to(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), HEADER_SIZE + 6 * 8 + 4);
+        assert_eq!(&buffer[..8], b"MTRX\x01\x00\x02\x02");
+        assert_eq!(f64::read_le(&buffer[HEADER_SIZE + 8..]), 4.);
+        assert_eq!(Matrix::<f64>::read_from(buffer.as_slice()).unwrap(), a);
+    }
+
+    #[test]
+    fn test_large_matrix_round_trip() {
+        let mut a = Matrix::from_elem(0i64, 150, 90);
+        for x in 0..150 {
+            for y in 0..90 {
+                a[(x, y)] = (x * 1000 + y) as i64 - 50_000;
+            }
+        }
+        let mut buffer = Vec::new();
+        a.write_to(&mut buffer).unwrap();
+        assert_eq!(Matrix::<i64>::read_from(buffer.as_slice()).unwrap(), a);
+    }
+
+    #[test]
+    fn test_vector_round_trip() {
+        let v: Vector<i32> = Vector::from([7, -3, 12, 0]);
+        let mut buffer = Vec::new();
+        v.write_to(&mut buffer).unwrap();
+        assert_eq!(Vector::<i32>::read_from(buffer.as_slice()).unwrap(), v);
+        assert!(matches!(
+            Matrix::<i32>::read_from(buffer.as_slice()),
+            Err(BinaryError::RankMismatch { expected: 2, found: 1 })
+        ));
+
+        let mut buffer = Vec::new();
+        Matrix::from_rows([[1], [2]]).write_to(&mut buffer).unwrap();
+        assert!(matches!(
+            Vector::<i32>::read_from(buffer.as_slice()),
+            Err(BinaryError::RankMismatch { expected: 1, found: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_header_errors() {
+        let mut buffer = Vec::new();
+        Matrix::from_rows([[1f32, 2.]]).write_to(&mut buffer).unwrap();
+
+        assert!(matches!(
+            Matrix::<f64>::read_from(buffer.as_slice()),
+            Err(BinaryError::TypeMismatch { expected: "f64", found: "f32" })
+        ));
+        assert!(matches!(Matrix::<f32>::read_from(&b"NUMPY"[..]), Err(BinaryError::InvalidMagic)));
+        assert!(matches!(Matrix::<f32>::read_from(&b"MT"[..]), Err(BinaryError::InvalidMagic)));
+
+        let mut newer = buffer.clone();
+        newer[4] = 2;
+        assert!(matches!(Matrix::<f32>::read_from(newer.as_slice()), Err(BinaryError::UnsupportedVersion(2))));
+
+        let mut rank = buffer.clone();
+        rank[7] = 3;
+        assert!(matches!(
+            Matrix::<f32>::read_from(rank.as_slice()),
+            Err(BinaryError::RankMismatch { expected: 2, found: 3 })
+        ));
+
+        let mut wide = Vec::new();
+        Vector::from([1f32, 2.]).write_to(&mut wide).unwrap();
+        wide[8] = 2;
+        assert!(matches!(Vector::<f32>::read_from(wide.as_slice()), Err(BinaryError::InvalidShape { width: 2, height: 2 })));
+
+        let mut huge = buffer.clone();
+        huge[8..24].fill(0xff);
+        assert!(matches!(Matrix::<f32>::read_from(huge.as_slice()), Err(BinaryError::InvalidShape { .. })));
+    }
+
+    #[test]
+    fn test_payload_errors() {
+        let mut buffer = Vec::new();
+        Vector::from([1., 2., 3.]).write_to(&mut buffer).unwrap();
+
+        let mut corrupt = buffer.clone();
+        corrupt[HEADER_SIZE + 3] ^= 0x10;
+        assert!(matches!(
+            Vector::<f64>::read_from(corrupt.as_slice()),
+            Err(BinaryError::ChecksumMismatch { .. })
+        ));
+        assert!(matches!(
+            Vector::<f64>::read_from(&buffer[..buffer.len() - 2]),
+            Err(BinaryError::Truncated)
+        ));
+        assert!(matches!(Vector::<f64>::read_from(&buffer[..HEADER_SIZE + 5]), Err(BinaryError::Truncated)));
+    }
+}
@@ -0,0 +1,253 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::Field;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+use std::{error, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    /// Leading non-comment lines to skip, such as a header row.
+    pub header_rows: usize,
+    /// Lines starting with this character are ignored.
+    pub comment: Option<char>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            header_rows: 0,
+            comment: Some('#'),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CsvError {
+    Io(io::Error),
+    /// A field that does not parse as an element; `line` and `column` are 1-based.
+    Parse { line: usize, column: usize, value: String },
+    Ragged { line: usize, expected: usize, found: usize },
+    /// `Vector::from_csv` got data that is neither a single row nor a single column.
+    NotAVector { rows: usize, columns: usize },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(error) => write!(f, "I/O error: {error}"),
+            CsvError::Parse { line, column, value } => write!(f, "line {line}, column {column}: cannot parse {value:?}"),
+            CsvError::Ragged { line, expected, found } => write!(f, "line {line}: expected {expected} fields, found {found}"),
+            CsvError::NotAVector { rows, columns } => write!(f, "expected a single row or column, found {rows}x{columns}"),
+        }
+    }
+}
+
+impl error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CsvError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(value: io::Error) -> Self {
+        CsvError::Io(value)
+    }
+}
+
+fn read_rows<K: Field + FromStr, R: Read>(reader: R, options: &CsvOptions) -> Result<Vec<Vec<K>>, CsvError> {
+    let mut rows: Vec<Vec<K>> = Vec::new();
+    let mut skipped = 0;
+
+    for (index, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        let number = index + 1;
+        if line.trim().is_empty() || options.comment.is_some_and(|c| line.trim_start().starts_with(c)) {
+            continue;
+        }
+        if skipped < options.header_rows {
+            skipped += 1;
+            continue;
+        }
+
+        let row = line
+            .split(options.delimiter)
+            .enumerate()
+            .map(|(column, field)| {
+                let value = field.trim().trim_matches('"').trim();
+                value.parse().map_err(|_| CsvError::Parse {
+                    line: number,
+                    column: column + 1,
+                    value: value.to_string(),
+                })
+            })
+            .collect::<Result<Vec<K>, CsvError>>()?;
+
+        if let Some(first) = rows.first()
+            && first.len() != row.len()
+        {
+            return Err(CsvError::Ragged { line: number, expected: first.len(), found: row.len() });
+        }
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+impl<K: Field + FromStr> Matrix<K> {
+    /// Reads delimited text, one matrix row per line.
+    pub fn from_csv<R: Read>(reader: R, options: &CsvOptions) -> Result<Matrix<K>, CsvError> {
+        let rows = read_rows(reader, options)?;
+        let width = rows.first().map_or(0, Vec::len);
+        let mut result = Matrix::from_elem(K::zero(), width, rows.len());
+
+        for (y, row) in rows.into_iter().enumerate() {
+            for (x, value) in row.into_iter().enumerate() {
+                result[(x, y)] = value;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    /// Writes one matrix row per line, separated by `options.delimiter`.
+    pub fn to_csv<W: Write>(&self, mut writer: W, options: &CsvOptions) -> io::Result<()> {
+        let (width, height) = self.shape();
+
+        for y in 0..height {
+            for x in 0..width {
+                if x > 0 {
+                    write!(writer, "{}", options.delimiter)?;
+                }
+                write!(writer, "{}", self[(x, y)])?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<K: Field + FromStr> Vector<K> {
+    /// Reads either a single row or a single column of delimited text.
+    pub fn from_csv<R: Read>(reader: R, options: &CsvOptions) -> Result<Vector<K>, CsvError> {
+        let rows = read_rows(reader, options)?;
+        let columns = rows.first().map_or(0, Vec::len);
+        let values: Vec<K> = match (rows.len(), columns) {
+            (1, _) | (_, 1) | (0, 0) => rows.into_iter().flatten().collect(),
+            (rows, columns) => return Err(CsvError::NotAVector { rows, columns }),
+        };
+
+        let mut result = Vector::from_elem(K::zero(), values.len());
+        for (i, value) in values.into_iter().enumerate() {
+            result[i] = value;
+        }
+
+        Ok(result)
+    }
+}
+
+impl<K: Field> Vector<K> {
+    /// Writes the elements as a single row, separated by `options.delimiter`.
+    pub fn to_csv<W: Write>(&self, mut writer: W, options: &CsvOptions) -> io::Result<()> {
+        for i in 0..self.size() {
+            if i > 0 {
+                write!(writer, "{}", options.delimiter)?;
+            }
+            write!(writer, "{}", self[i])?;
+        }
+        if self.size() > 0 {
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_from_csv() {
+        let text = "# exported\nx,y,z\n1, 2, 3\n\n4,5,\"6.5\"\n";
+        let options = CsvOptions { header_rows: 1, ..CsvOptions::default() };
+        assert_eq!(Matrix::from_csv(text.as_bytes(), &options).unwrap(), Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.5],
+        ]));
+    }
+
+    #[test]
+    fn test_matrix_from_csv_delimiter() {
+        let text = "1;2\n3;4\n% note\n";
+        let options = CsvOptions { delimiter: ';', header_rows: 0, comment: Some('%') };
+        assert_eq!(Matrix::<i32>::from_csv(text.as_bytes(), &options).unwrap(), Matrix::from_rows([
+            [1, 2],
+            [3, 4],
+        ]));
+    }
+
+    #[test]
+    fn test_matrix_from_csv_errors() {
+        let options = CsvOptions::default();
+        match Matrix::<f64>::from_csv("1,2\n3,x\n".as_bytes(), &options) {
+            Err(CsvError::Parse { line, column, value }) => assert_eq!((line, column, value.as_str()), (2, 2, "x")),
+            other => panic!("unexpected result {other:?}"),
+        }
+        assert!(matches!(
+            Matrix::<f64>::from_csv("# c\n1,2\n3\n".as_bytes(), &options),
+            Err(CsvError::Ragged { line: 3, expected: 2, found: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_matrix_to_csv_round_trip() {
+        let a = Matrix::from_rows([
+            [1.5, -2., 3.],
+            [4., 5., 6.],
+        ]);
+        let options = CsvOptions { delimiter: '\t', ..CsvOptions::default() };
+        let mut buffer = Vec::new();
+        a.to_csv(&mut buffer, &options).unwrap();
+        assert_eq!(String::from_utf8(buffer.clone()).unwrap(), "1.5\t-2\t3\n4\t5\t6\n");
+        assert_eq!(Matrix::from_csv(buffer.as_slice(), &options).unwrap(), a);
+    }
+
+    #[test]
+    fn test_vector_csv() {
+        let options = CsvOptions::default();
+        assert_eq!(Vector::from_csv("1,2,3\n".as_bytes(), &options).unwrap(), Vector::from([1., 2., 3.]));
+        assert_eq!(Vector::from_csv("1\n2\n3\n".as_bytes(), &options).unwrap(), Vector::from([1., 2., 3.]));
+        assert!(matches!(
+            Vector::<f64>::from_csv("1,2\n3,4\n".as_bytes(), &options),
+            Err(CsvError::NotAVector { rows: 2, columns: 2 })
+        ));
+
+        let mut buffer = Vec::new();
+        Vector::from([1., 2.5]).to_csv(&mut buffer, &options).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "1,2.5\n");
+    }
+
+    #[test]
+    fn test_vector_to_csv_round_trip() {
+        let v = Vector::from([1.5, -2., 3.]);
+        let options = CsvOptions { delimiter: ';', ..CsvOptions::default() };
+        let mut buffer = Vec::new();
+        v.to_csv(&mut buffer, &options).unwrap();
+        assert_eq!(String::from_utf8(buffer.clone()).unwrap(), "1.5;-2;3\n");
+        assert_eq!(Vector::from_csv(buffer.as_slice(), &options).unwrap(), v);
+
+        let mut buffer = Vec::new();
+        Vector::<f64>::from_elem(0., 0).to_csv(&mut buffer, &options).unwrap();
+        assert_eq!(Vector::<f64>::from_csv(buffer.as_slice(), &options).unwrap().size(), 0);
+    }
+}
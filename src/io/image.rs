@@ -0,0 +1,258 @@
+#![allow(dead_code)]
+
+use crate::core::Matrix;
+use crate::traits::{Field, ToF64};
+use std::io::{self, Write};
+
+/// Keeps plain PPM/PGM lines under the 70 characters the Netpbm spec asks for.
+const PIXELS_PER_LINE: usize = 5;
+/// Spy plot geometry, in SVG user units.
+const SPY_CELL: usize = 10;
+const SPY_MARGIN: usize = 10;
+const SPY_LABEL: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colormap {
+    Grayscale,
+    /// Perceptually uniform, dark blue through green to yellow.
+    Viridis,
+    /// Black through red and yellow to white.
+    Hot,
+    /// Diverging blue to red through light gray, for data centered on zero.
+    CoolWarm,
+}
+
+impl Colormap {
+    fn stops(&self) -> &'static [[u8; 3]] {
+        match self {
+            Colormap::Grayscale => &[[0, 0, 0], [255, 255, 255]],
+            Colormap::Viridis => &[
+                [68, 1, 84],
+                [72, 40, 120],
+                [62, 74, 137],
+                [49, 104, 142],
+                [38, 130, 142],
+                [31, 158, 137],
+                [53, 183, 121],
+                [109, 205, 89],
+                [180, 222, 44],
+                [253, 231, 37],
+            ],
+            Colormap::Hot => &[[0, 0, 0], [230, 0, 0], [255, 210, 0], [255, 255, 255]],
+            Colormap::CoolWarm => &[[59, 76, 192], [221, 221, 221], [180, 4, 38]],
+        }
+    }
+
+    /// The color at `t` in [0, 1], interpolating linearly between stops.
+    pub fn color(&self, t: f64) -> [u8; 3] {
+        let stops = self.stops();
+        let t = if t.is_nan() { 0. } else { t.clamp(0., 1.) };
+        let position = t * (stops.len() - 1) as f64;
+        let i = (position.floor() as usize).min(stops.len() - 2);
+        let fraction = position - i as f64;
+
+        let mut color = [0; 3];
+        for channel in 0..3 {
+            let (a, b) = (stops[i][channel] as f64, stops[i + 1][channel] as f64);
+            color[channel] = (a + (b - a) * fraction).round() as u8;
+        }
+
+        color
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatmapOptions {
+    pub colormap: Colormap,
+    /// Values mapped to the two ends of the colormap; values outside are clamped.
+    /// Defaults to the matrix's own minimum and maximum.
+    pub range: Option<(f64, f64)>,
+    /// Side of the square block of pixels drawn for each element.
+    pub scale: usize,
+}
+
+impl Default for HeatmapOptions {
+    fn default() -> Self {
+        HeatmapOptions {
+            colormap: Colormap::Viridis,
+            range: None,
+            scale: 1,
+        }
+    }
+}
+
+/// Element values normalized to [0, 1] over the requested or observed range, row by row.
+fn normalized<K: Field + ToF64>(matrix: &Matrix<K>, options: &HeatmapOptions) -> Vec<Vec<f64>> {
+    let (width, height) = matrix.shape();
+    let values: Vec<Vec<f64>> = (0..height).map(|y| (0..width).map(|x| matrix[(x, y)].to_f64()).collect()).collect();
+
+    let (min, max) = options.range.unwrap_or_else(|| {
+        values
+            .iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| (min.min(value), max.max(value)))
+    });
+
+    values
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|value| if max > min { ((value - min) / (max - min)).clamp(0., 1.) } else { 0.5 })
+                .collect()
+        })
+        .collect()
+}
+
+/// Writes a plain (ASCII) Netpbm image, each element a `scale`-sized block of `pixel` samples.
+fn write_netpbm<W: Write>(
+    mut writer: W,
+    magic: &str,
+    values: &[Vec<f64>],
+    scale: usize,
+    pixel: impl Fn(f64) -> String,
+) -> io::Result<()> {
+    assert!(scale > 0, "Heatmap scale must be positive");
+    let width = values.first().map_or(0, Vec::len);
+    writeln!(writer, "{magic}\n{} {}\n255", width * scale, values.len() * scale)?;
+
+    for row in values {
+        let line: Vec<String> = row.iter().flat_map(|&t| std::iter::repeat_n(pixel(t), scale)).collect();
+        for _ in 0..scale {
+            for chunk in line.chunks(PIXELS_PER_LINE) {
+                writeln!(writer, "{}", chunk.join("  "))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl<K: Field + ToF64> Matrix<K> {
+    /// Writes a plain-text PPM (P3) heatmap, one pixel block per element, rows top to bottom.
+    pub fn write_heatmap_ppm<W: Write>(&self, writer: W, options: &HeatmapOptions) -> io::Result<()> {
+        write_netpbm(writer, "P3", &normalized(self, options), options.scale, |t| {
+            let [r, g, b] = options.colormap.color(t);
+            format!("{r} {g} {b}")
+        })
+    }
+
+    /// Writes a plain-text PGM (P2) heatmap. The colormap is ignored: low values are black.
+    pub fn write_heatmap_pgm<W: Write>(&self, writer: W, options: &HeatmapOptions) -> io::Result<()> {
+        write_netpbm(writer, "P2", &normalized(self, options), options.scale, |t| {
+            Colormap::Grayscale.color(t)[0].to_string()
+        })
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    /// Writes an SVG of the non-zero pattern, a dot per non-zero element, like MATLAB's `spy`.
+    pub fn write_spy_svg<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let (width, height) = self.shape();
+        let (frame_width, frame_height) = (width * SPY_CELL, height * SPY_CELL);
+        let (svg_width, svg_height) = (frame_width + 2 * SPY_MARGIN, frame_height + 2 * SPY_MARGIN + SPY_LABEL);
+        let radius = SPY_CELL as f64 * 0.35;
+
+        writeln!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{svg_width}" height="{svg_height}" viewBox="0 0 {svg_width} {svg_height}">"#
+        )?;
+        writeln!(
+            writer,
+            r#"  <rect x="{SPY_MARGIN}" y="{SPY_MARGIN}" width="{frame_width}" height="{frame_height}" fill="white" stroke="black"/>"#
+        )?;
+
+        let mut nnz = 0;
+        for y in 0..height {
+            for x in 0..width {
+                if self[(x, y)] == K::zero() {
+                    continue;
+                }
+                let cx = (SPY_MARGIN + x * SPY_CELL) as f64 + SPY_CELL as f64 / 2.;
+                let cy = (SPY_MARGIN + y * SPY_CELL) as f64 + SPY_CELL as f64 / 2.;
+                writeln!(writer, r#"  <circle cx="{cx}" cy="{cy}" r="{radius}" fill="navy"/>"#)?;
+                nnz += 1;
+            }
+        }
+
+        writeln!(
+            writer,
+            r#"  <text x="{}" y="{}" text-anchor="middle" font-family="sans-serif" font-size="12">nz = {nnz}</text>"#,
+            svg_width / 2,
+            svg_height - SPY_MARGIN
+        )?;
+        writeln!(writer, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colormap() {
+        assert_eq!(Colormap::Grayscale.color(0.), [0, 0, 0]);
+        assert_eq!(Colormap::Grayscale.color(0.5), [128, 128, 128]);
+        assert_eq!(Colormap::Grayscale.color(2.), [255, 255, 255]);
+        assert_eq!(Colormap::Grayscale.color(f64::NAN), [0, 0, 0]);
+        assert_eq!(Colormap::Viridis.color(0.), [68, 1, 84]);
+        assert_eq!(Colormap::Viridis.color(1.), [253, 231, 37]);
+        assert_eq!(Colormap::CoolWarm.color(0.5), [221, 221, 221]);
+    }
+
+    #[test]
+    fn test_heatmap_ppm() {
+        let a = Matrix::from_rows([
+            [0., 1.],
+            [2., 4.],
+        ]);
+        let options = HeatmapOptions { colormap: Colormap::Grayscale, ..HeatmapOptions::default() };
+        let mut buffer = Vec::new();
+        a.write_heatmap_ppm(&mut buffer, &options).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "P3\n2 2\n255\n0 0 0  64 64 64\n128 128 128  255 255 255\n"
+        );
+
+        let options = HeatmapOptions { colormap: Colormap::Hot, range: Some((0., 1.)), scale: 3 };
+        let mut buffer = Vec::new();
+        a.write_heatmap_ppm(&mut buffer, &options).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[1], "6 6");
+        // Six pixels per image row wrap onto two lines, three image rows per matrix row
+        assert_eq!(lines.len(), 3 + 6 * 2);
+        assert_eq!(lines[3], "0 0 0  0 0 0  0 0 0  255 255 255  255 255 255");
+        assert_eq!(lines[4], "255 255 255");
+        assert!(lines[9..].iter().all(|line| line.split("  ").all(|pixel| pixel == "255 255 255")));
+    }
+
+    #[test]
+    fn test_heatmap_pgm() {
+        let a = Matrix::from_rows([[-1, 0, 1]]);
+        let mut buffer = Vec::new();
+        a.write_heatmap_pgm(&mut buffer, &HeatmapOptions::default()).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "P2\n3 1\n255\n0  128  255\n");
+
+        let mut buffer = Vec::new();
+        Matrix::from_elem(7, 2, 1).write_heatmap_pgm(&mut buffer, &HeatmapOptions::default()).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "P2\n2 1\n255\n128  128\n");
+    }
+
+    #[test]
+    fn test_spy_svg() {
+        let a = Matrix::from_rows([
+            [1., 0., 0.],
+            [0., 0., 2.],
+        ]);
+        let mut buffer = Vec::new();
+        a.write_spy_svg(&mut buffer).unwrap();
+        let svg = String::from_utf8(buffer).unwrap();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="50" height="60""#));
+        assert_eq!(svg.matches("<circle").count(), 2);
+        assert!(svg.contains(r#"<circle cx="15" cy="15""#));
+        assert!(svg.contains(r#"<circle cx="35" cy="25""#));
+        assert!(svg.contains(">nz = 2</text>"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
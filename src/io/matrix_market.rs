@@ -0,0 +1,458 @@
+#![allow(dead_code)]
+
+use crate::core::Matrix;
+use crate::traits::Field;
+use std::cell::Cell;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+use std::{error, fmt};
+
+#[derive(Debug)]
+pub enum MatrixMarketError {
+    Io(io::Error),
+    /// The `%%MatrixMarket` banner is missing or names an unknown object, format, field or symmetry.
+    InvalidHeader(String),
+    InvalidSize { line: usize },
+    InvalidEntry { line: usize },
+    IndexOutOfBounds { line: usize },
+    /// A complex entry with a non-zero imaginary part, which `Matrix<K>` cannot hold.
+    ComplexValue { line: usize },
+    EntryCount { expected: usize, found: usize },
+}
+
+impl fmt::Display for MatrixMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixMarketError::Io(error) => write!(f, "I/O error: {error}"),
+            MatrixMarketError::InvalidHeader(header) => write!(f, "invalid Matrix Market header: {header:?}"),
+            MatrixMarketError::InvalidSize { line } => write!(f, "line {line}: invalid size line"),
+            MatrixMarketError::InvalidEntry { line } => write!(f, "line {line}: invalid entry"),
+            MatrixMarketError::IndexOutOfBounds { line } => write!(f, "line {line}: index out of bounds"),
+            MatrixMarketError::ComplexValue { line } => write!(f, "line {line}: complex value has a non-zero imaginary part"),
+            MatrixMarketError::EntryCount { expected, found } => write!(f, "expected {expected} entries, found {found}"),
+        }
+    }
+}
+
+impl error::Error for MatrixMarketError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            MatrixMarketError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MatrixMarketError {
+    fn from(value: io::Error) -> Self {
+        MatrixMarketError::Io(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixMarketFormat {
+    /// Every element, column by column.
+    Array,
+    /// Only the non-zero elements, as `row column value` triplets.
+    Coordinate,
+}
+
+/// Element types with a Matrix Market field name.
+pub trait MatrixMarketElement: Field {
+    const FIELD: &'static str;
+}
+
+macro_rules! impl_matrix_market_element {
+    ($($t:ty => $field:expr),*) => {
+        $(
+        impl MatrixMarketElement for $t {
+            const FIELD: &'static str = $field;
+        }
+        )*
+    };
+}
+
+impl_matrix_market_element!(f32 => "real", f64 => "real");
+impl_matrix_market_element!(i8 => "integer", i16 => "integer", i32 => "integer", i64 => "integer", i128 => "integer");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueField {
+    Real,
+    Integer,
+    Complex,
+    Pattern,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+    Hermitian,
+}
+
+fn parse_header(header: &str) -> Result<(MatrixMarketFormat, ValueField, Symmetry), MatrixMarketError> {
+    let invalid = || MatrixMarketError::InvalidHeader(header.to_string());
+    let words: Vec<String> = header.split_whitespace().map(str::to_lowercase).collect();
+    if words.len() != 5 || words[0] != "%%matrixmarket" || words[1] != "matrix" {
+        return Err(invalid());
+    }
+
+    let format = match words[2].as_str() {
+        "array" => MatrixMarketFormat::Array,
+        "coordinate" => MatrixMarketFormat::Coordinate,
+        _ => return Err(invalid()),
+    };
+    let field = match words[3].as_str() {
+        "real" | "double" => ValueField::Real,
+        "integer" => ValueField::Integer,
+        "complex" => ValueField::Complex,
+        "pattern" => ValueField::Pattern,
+        _ => return Err(invalid()),
+    };
+    let symmetry = match words[4].as_str() {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        "hermitian" => Symmetry::Hermitian,
+        _ => return Err(invalid()),
+    };
+
+    if (format == MatrixMarketFormat::Array && field == ValueField::Pattern)
+        || (symmetry == Symmetry::Hermitian && field != ValueField::Complex)
+        || (symmetry == Symmetry::SkewSymmetric && field == ValueField::Pattern)
+    {
+        return Err(invalid());
+    }
+
+    Ok((format, field, symmetry))
+}
+
+fn parse_value<'a, K, I>(field: ValueField, tokens: &mut I, line: usize) -> Result<K, MatrixMarketError>
+where
+    K: Field + FromStr,
+    I: Iterator<Item = &'a str>,
+{
+    let mut next = || -> Result<K, MatrixMarketError> {
+        tokens
+            .next()
+            .and_then(|token| token.parse().ok())
+            .ok_or(MatrixMarketError::InvalidEntry { line })
+    };
+
+    match field {
+        ValueField::Pattern => Ok(K::one()),
+        ValueField::Real | ValueField::Integer => next(),
+        ValueField::Complex => {
+            let real = next()?;
+            if next()? != K::zero() {
+                return Err(MatrixMarketError::ComplexValue { line });
+            }
+            Ok(real)
+        }
+    }
+}
+
+impl<K: Field + FromStr> Matrix<K> {
+    /// Reads a Matrix Market file in array or coordinate format. Symmetric, skew-symmetric
+    /// and Hermitian files are expanded; pattern entries read as one.
+    pub fn read_matrix_market<R: Read>(reader: R) -> Result<Matrix<K>, MatrixMarketError> {
+        let mut lines = BufReader::new(reader).lines().enumerate().map(|(i, line)| (i + 1, line));
+
+        let header = match lines.next() {
+            Some((_, line)) => line?,
+            None => return Err(MatrixMarketError::InvalidHeader(String::new())),
+        };
+        let (format, field, symmetry) = parse_header(&header)?;
+
+        // Skip comments and blank lines, keeping line numbers for errors
+        let last_line = Cell::new(1);
+        let mut content = lines.inspect(|(number, _)| last_line.set(*number)).filter_map(|(number, line)| match line {
+            Ok(line) if line.trim().is_empty() || line.starts_with('%') => None,
+            Ok(line) => Some(Ok((number, line))),
+            Err(error) => Some(Err(error)),
+        });
+
+        let (size_line, size) = content.next().transpose()?.ok_or_else(|| MatrixMarketError::InvalidSize { line: last_line.get() })?;
+        let size: Vec<usize> = size
+            .split_whitespace()
+            .map(|token| token.parse().map_err(|_| MatrixMarketError::InvalidSize { line: size_line }))
+            .collect::<Result<_, _>>()?;
+        let expected_size_len = if format == MatrixMarketFormat::Array { 2 } else { 3 };
+        if size.len() != expected_size_len {
+            return Err(MatrixMarketError::InvalidSize { line: size_line });
+        }
+        let (rows, cols) = (size[0], size[1]);
+        // The element buffer is allocated up front, so it must fit in isize::MAX bytes
+        let bytes = rows.checked_mul(cols).and_then(|len| len.checked_mul(size_of::<K>()));
+        if bytes.is_none_or(|bytes| bytes > isize::MAX as usize) || (symmetry != Symmetry::General && rows != cols) {
+            return Err(MatrixMarketError::InvalidSize { line: size_line });
+        }
+
+        let mut result = Matrix::from_elem(K::zero(), cols, rows);
+        let mut set = |row: usize, col: usize, value: K| {
+            result[(col, row)] = value;
+            if row != col {
+                match symmetry {
+                    Symmetry::General => {}
+                    Symmetry::Symmetric | Symmetry::Hermitian => result[(row, col)] = value,
+                    Symmetry::SkewSymmetric => result[(row, col)] = -value,
+                }
+            }
+        };
+
+        let (expected, found) = match format {
+            MatrixMarketFormat::Array => {
+                // Column-major; symmetric files store only the lower triangle
+                let positions: Vec<(usize, usize)> = (0..cols)
+                    .flat_map(|col| (0..rows).map(move |row| (row, col)))
+                    .filter(|&(row, col)| match symmetry {
+                        Symmetry::General => true,
+                        Symmetry::Symmetric | Symmetry::Hermitian => row >= col,
+                        Symmetry::SkewSymmetric => row > col,
+                    })
+                    .collect();
+
+                let mut found = 0;
+                for entry in content {
+                    let (line, text) = entry?;
+                    let &(row, col) = positions.get(found).ok_or(MatrixMarketError::EntryCount {
+                        expected: positions.len(),
+                        found: found + 1,
+                    })?;
+                    let mut tokens = text.split_whitespace();
+                    let value = parse_value(field, &mut tokens, line)?;
+                    if tokens.next().is_some() {
+                        return Err(MatrixMarketError::InvalidEntry { line });
+                    }
+                    set(row, col, value);
+                    found += 1;
+                }
+                (positions.len(), found)
+            }
+            MatrixMarketFormat::Coordinate => {
+                let mut found = 0;
+                for entry in content {
+                    let (line, text) = entry?;
+                    let mut tokens = text.split_whitespace();
+                    let mut index = || -> Result<usize, MatrixMarketError> {
+                        tokens
+                            .next()
+                            .and_then(|token| token.parse::<usize>().ok())
+                            .ok_or(MatrixMarketError::InvalidEntry { line })
+                    };
+                    let (row, col) = (index()?, index()?);
+                    if row == 0 || col == 0 || row > rows || col > cols {
+                        return Err(MatrixMarketError::IndexOutOfBounds { line });
+                    }
+                    let value = parse_value(field, &mut tokens, line)?;
+                    if tokens.next().is_some() {
+                        return Err(MatrixMarketError::InvalidEntry { line });
+                    }
+                    set(row - 1, col - 1, value);
+                    found += 1;
+                }
+                (size[2], found)
+            }
+        };
+
+        if expected != found {
+            return Err(MatrixMarketError::EntryCount { expected, found });
+        }
+
+        Ok(result)
+    }
+}
+
+impl<K: MatrixMarketElement> Matrix<K> {
+    /// Writes the matrix in `general` Matrix Market format, labelled `real` or `integer`
+    /// after `K`. The coordinate format writes only the non-zero elements.
+    pub fn write_matrix_market<W: Write>(&self, mut writer: W, format: MatrixMarketFormat) -> io::Result<()> {
+        let (width, height) = self.shape();
+
+        match format {
+            MatrixMarketFormat::Array => {
+                writeln!(writer, "%%MatrixMarket matrix array {} general", K::FIELD)?;
+                writeln!(writer, "{height} {width}")?;
+                for x in 0..width {
+                    for y in 0..height {
+                        writeln!(writer, "{}", self[(x, y)])?;
+                    }
+                }
+            }
+            MatrixMarketFormat::Coordinate => {
+                let non_zeros = self.iter().filter(|&value| value != K::zero()).count();
+                writeln!(writer, "%%MatrixMarket matrix coordinate {} general", K::FIELD)?;
+                writeln!(writer, "{height} {width} {non_zeros}")?;
+                for x in 0..width {
+                    for y in 0..height {
+                        if self[(x, y)] != K::zero() {
+                            writeln!(writer, "{} {} {}", y + 1, x + 1, self[(x, y)])?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(text: &str) -> Result<Matrix<f64>, MatrixMarketError> {
+        Matrix::read_matrix_market(text.as_bytes())
+    }
+
+    #[test]
+    fn test_read_array() {
+        let text = "%%MatrixMarket matrix array real general\n% comment\n2 3\n1\n4\n2\n5\n3\n6\n";
+        assert_eq!(read(text).unwrap(), Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]));
+    }
+
+    #[test]
+    fn test_read_array_symmetric() {
+        let text = "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n";
+        assert_eq!(read(text).unwrap(), Matrix::from_rows([
+            [1., 2.],
+            [2., 3.],
+        ]));
+
+        let text = "%%MatrixMarket matrix array integer skew-symmetric\n3 3\n1\n2\n3\n";
+        assert_eq!(read(text).unwrap(), Matrix::from_rows([
+            [0., -1., -2.],
+            [1., 0., -3.],
+            [2., 3., 0.],
+        ]));
+    }
+
+    #[test]
+    fn test_read_coordinate() {
+        let text = "%%MatrixMarket matrix coordinate real general\n%\n\n2 3 2\n1 1 1.5\n2 3 -2e1\n";
+        assert_eq!(read(text).unwrap(), Matrix::from_rows([
+            [1.5, 0., 0.],
+            [0., 0., -20.],
+        ]));
+
+        let text = "%%MatrixMarket matrix coordinate pattern symmetric\n3 3 2\n2 1\n3 3\n";
+        assert_eq!(read(text).unwrap(), Matrix::from_rows([
+            [0., 1., 0.],
+            [1., 0., 0.],
+            [0., 0., 1.],
+        ]));
+
+        let text = "%%MatrixMarket matrix coordinate complex hermitian\n2 2 2\n1 1 4 0\n2 1 1 0\n";
+        assert_eq!(read(text).unwrap(), Matrix::from_rows([
+            [4., 1.],
+            [1., 0.],
+        ]));
+
+        let text = "%%MatrixMarket matrix coordinate integer general\n2 2 1\n2 1 7\n";
+        assert_eq!(Matrix::<i32>::read_matrix_market(text.as_bytes()).unwrap(), Matrix::from_rows([
+            [0, 0],
+            [7, 0],
+        ]));
+    }
+
+    #[test]
+    fn test_read_errors() {
+        assert!(matches!(read(""), Err(MatrixMarketError::InvalidHeader(_))));
+        assert!(matches!(read("%%MatrixMarket matrix sparse real general\n"), Err(MatrixMarketError::InvalidHeader(_))));
+        assert!(matches!(read("%%MatrixMarket matrix array pattern general\n"), Err(MatrixMarketError::InvalidHeader(_))));
+        assert!(matches!(read("%%MatrixMarket vector array real general\n"), Err(MatrixMarketError::InvalidHeader(_))));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n2\n"),
+            Err(MatrixMarketError::InvalidSize { line: 2 })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n"),
+            Err(MatrixMarketError::IndexOutOfBounds { line: 3 })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 one\n"),
+            Err(MatrixMarketError::InvalidEntry { line: 3 })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate complex general\n2 2 1\n1 1 1 1\n"),
+            Err(MatrixMarketError::ComplexValue { line: 3 })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n2 1\n1\n"),
+            Err(MatrixMarketError::EntryCount { expected: 2, found: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_read_size_overflow() {
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate real general\n4294967296 4294967296 0\n"),
+            Err(MatrixMarketError::InvalidSize { line: 2 })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate real general\n2147483648 2147483648 1\n"),
+            Err(MatrixMarketError::InvalidSize { line: 2 })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate real general\n1073741824 1073741824 0\n"),
+            Err(MatrixMarketError::InvalidSize { line: 2 })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n% comment\n\n"),
+            Err(MatrixMarketError::InvalidSize { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_write_array_round_trip() {
+        let a = Matrix::from_rows([
+            [1.5, 2., 3.],
+            [4., -5., 6.25],
+        ]);
+        let mut buffer = Vec::new();
+        a.write_matrix_market(&mut buffer, MatrixMarketFormat::Array).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            "%%MatrixMarket matrix array real general\n2 3\n1.5\n4\n2\n-5\n3\n6.25\n"
+        );
+        assert_eq!(read(std::str::from_utf8(&buffer).unwrap()).unwrap(), a);
+
+        let a = Matrix::from_rows([
+            [1, -2],
+            [3, 4],
+        ]);
+        let mut buffer = Vec::new();
+        a.write_matrix_market(&mut buffer, MatrixMarketFormat::Array).unwrap();
+        assert!(buffer.starts_with(b"%%MatrixMarket matrix array integer general\n"));
+        assert_eq!(Matrix::<i32>::read_matrix_market(buffer.as_slice()).unwrap(), a);
+    }
+
+    #[test]
+    fn test_write_coordinate_round_trip() {
+        let a = Matrix::from_rows([
+            [0., 2.5, 0.],
+            [-1., 0., 0.],
+        ]);
+        let mut buffer = Vec::new();
+        a.write_matrix_market(&mut buffer, MatrixMarketFormat::Coordinate).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            "%%MatrixMarket matrix coordinate real general\n2 3 2\n2 1 -1\n1 2 2.5\n"
+        );
+        assert_eq!(read(std::str::from_utf8(&buffer).unwrap()).unwrap(), a);
+
+        let a = Matrix::from_rows([
+            [0, 0, 7],
+            [0, 0, 0],
+            [0, 9, 0],
+        ]);
+        let mut buffer = Vec::new();
+        a.write_matrix_market(&mut buffer, MatrixMarketFormat::Coordinate).unwrap();
+        assert!(buffer.starts_with(b"%%MatrixMarket matrix coordinate integer general\n3 3 2\n"));
+        assert_eq!(Matrix::<i32>::read_matrix_market(buffer.as_slice()).unwrap(), a);
+    }
+}
@@ -0,0 +1,20 @@
+mod binary;
+mod crc32;
+mod csv;
+mod image;
+mod matrix_market;
+mod npy;
+mod render;
+
+#[allow(unused_imports)]
+pub use binary::*;
+#[allow(unused_imports)]
+pub use csv::*;
+#[allow(unused_imports)]
+pub use image::*;
+#[allow(unused_imports)]
+pub use matrix_market::*;
+#[allow(unused_imports)]
+pub use npy::*;
+#[allow(unused_imports)]
+pub use render::*;
@@ -0,0 +1,589 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::io::crc32::crc32;
+use crate::traits::{Field, LeBytes};
+use std::io::{self, Read, Write};
+use std::{error, fmt};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Element types with a NumPy dtype descriptor.
+pub trait NpyElement: Field + LeBytes {
+    const DESCR: &'static str;
+}
+
+macro_rules! impl_npy_element {
+    ($($t:ty => $descr:expr),*) => {
+        $(
+        impl NpyElement for $t {
+            const DESCR: &'static str = $descr;
+        }
+        )*
+    };
+}
+
+impl_npy_element!(f32 => "<f4", f64 => "<f8", i8 => "|i1", i16 => "<i2", i32 => "<i4", i64 => "<i8");
+
+#[derive(Debug)]
+pub enum NpyError {
+    Io(io::Error),
+    InvalidMagic,
+    InvalidHeader(String),
+    DtypeMismatch { expected: &'static str, found: String },
+    /// Neither 1-D nor 2-D, or too large to address.
+    UnsupportedShape(Vec<usize>),
+    /// The payload is shorter than the header's shape requires.
+    Truncated,
+    InvalidArchive(&'static str),
+    MissingEntry(String),
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NpyError::Io(error) => write!(f, "I/O error: {error}"),
+            NpyError::InvalidMagic => write!(f, "not a .npy file"),
+            NpyError::InvalidHeader(header) => write!(f, "invalid .npy header: {header:?}"),
+            NpyError::DtypeMismatch { expected, found } => write!(f, "expected dtype {expected}, found {found}"),
+            NpyError::UnsupportedShape(shape) => write!(f, "unsupported array shape {shape:?}"),
+            NpyError::Truncated => write!(f, "array data is truncated"),
+            NpyError::InvalidArchive(reason) => write!(f, "invalid .npz archive: {reason}"),
+            NpyError::MissingEntry(name) => write!(f, "no array named {name:?} in archive"),
+        }
+    }
+}
+
+impl error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            NpyError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(value: io::Error) -> Self {
+        NpyError::Io(value)
+    }
+}
+
+/// Raw column-major elements of a 1-D or 2-D array, with shape (height, width) as NumPy orders it.
+struct NpyArray<K> {
+    shape: Vec<usize>,
+    data: Vec<K>,
+}
+
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header.find(&format!("'{key}'")).or_else(|| header.find(&format!("\"{key}\"")))?;
+    let rest = header[start + key.len() + 2..].trim_start();
+    Some(rest.strip_prefix(':')?.trim_start())
+}
+
+fn parse_header(header: &str) -> Option<(String, bool, Vec<usize>)> {
+    let descr = header_value(header, "descr")?;
+    let quote = descr.chars().next().filter(|&c| c == '\'' || c == '"')?;
+    let descr = &descr[1..descr[1..].find(quote)? + 1];
+
+    let fortran_order = header_value(header, "fortran_order")?;
+    let fortran_order = if fortran_order.starts_with("True") {
+        true
+    } else if fortran_order.starts_with("False") {
+        false
+    } else {
+        return None;
+    };
+
+    let shape = header_value(header, "shape")?.strip_prefix('(')?;
+    let shape = shape[..shape.find(')')?]
+        .split(',')
+        .map(str::trim)
+        .filter(|dimension| !dimension.is_empty())
+        .map(|dimension| dimension.parse().ok())
+        .collect::<Option<Vec<usize>>>()?;
+
+    Some((descr.to_string(), fortran_order, shape))
+}
+
+fn read_array<K: NpyElement, R: Read>(mut reader: R) -> Result<NpyArray<K>, NpyError> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble).map_err(|_| NpyError::InvalidMagic)?;
+    if &preamble[..6] != MAGIC {
+        return Err(NpyError::InvalidMagic);
+    }
+
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        _ => return Err(NpyError::InvalidMagic),
+    };
+    let mut header = vec![0u8; header_len];
+    reader.read_exact(&mut header)?;
+    let header = String::from_utf8_lossy(&header).into_owned();
+
+    let (descr, fortran_order, shape) = parse_header(&header).ok_or_else(|| NpyError::InvalidHeader(header.clone()))?;
+    // '=' is native order, which NumPy never writes for multi-byte types on little-endian hosts
+    let normalized = descr.replacen('=', "<", 1).replacen('|', "<", 1);
+    if normalized != K::DESCR.replacen('|', "<", 1) {
+        return Err(NpyError::DtypeMismatch { expected: K::DESCR, found: descr });
+    }
+    let (height, width) = match shape[..] {
+        [size] => (size, 1),
+        [height, width] => (height, width),
+        _ => return Err(NpyError::UnsupportedShape(shape)),
+    };
+
+    let len = match height.checked_mul(width).and_then(|len| len.checked_mul(K::SIZE)) {
+        Some(len) => len,
+        None => return Err(NpyError::UnsupportedShape(shape)),
+    };
+
+    // Reading through `take` grows the buffer with the bytes actually present, so a
+    // hostile shape cannot force a huge allocation
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < len {
+        return Err(NpyError::Truncated);
+    }
+    let values: Vec<K> = bytes.chunks_exact(K::SIZE).map(K::read_le).collect();
+
+    let data = if fortran_order || width == 1 {
+        values
+    } else {
+        (0..width).flat_map(|x| (0..height).map(move |y| y * width + x)).map(|i| values[i]).collect()
+    };
+
+    Ok(NpyArray { shape, data })
+}
+
+fn write_array<K: NpyElement, W: Write>(mut writer: W, shape: &str, data: impl Iterator<Item = K>) -> io::Result<()> {
+    let mut header = format!("{{'descr': '{}', 'fortran_order': True, 'shape': ({shape}), }}", K::DESCR);
+    // Pad so the payload starts on a 64-byte boundary, ending the header with a newline
+    let unpadded = MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+    header.push('\n');
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    for value in data {
+        value.write_le(&mut bytes);
+    }
+
+    writer.write_all(&bytes)
+}
+
+impl<K: NpyElement> Matrix<K> {
+    /// Reads a 2-D `.npy` array in either memory order; a 1-D array becomes a single column.
+    pub fn read_npy<R: Read>(reader: R) -> Result<Matrix<K>, NpyError> {
+        let array = read_array::<K, R>(reader)?;
+        let (height, width) = (array.shape[0], array.shape.get(1).copied().unwrap_or(1));
+        let mut result = Matrix::from_elem(K::zero(), width, height);
+
+        for (i, value) in array.data.into_iter().enumerate() {
+            result[(i / height, i % height)] = value;
+        }
+
+        Ok(result)
+    }
+
+    /// Writes a 2-D `.npy` array with `fortran_order: True`, matching the column-major storage.
+    pub fn write_npy<W: Write>(&self, writer: W) -> io::Result<()> {
+        let (width, height) = self.shape();
+        let data = (0..width).flat_map(|x| (0..height).map(move |y| (x, y))).map(|index| self[index]);
+        write_array(writer, &format!("{height}, {width}"), data)
+    }
+}
+
+impl<K: NpyElement> Vector<K> {
+    /// Reads a 1-D `.npy` array, or a 2-D one with a single row or column.
+    pub fn read_npy<R: Read>(reader: R) -> Result<Vector<K>, NpyError> {
+        let array = read_array::<K, R>(reader)?;
+        if array.shape.len() == 2 && array.shape[0] != 1 && array.shape[1] != 1 {
+            return Err(NpyError::UnsupportedShape(array.shape));
+        }
+
+        let mut result = Vector::from_elem(K::zero(), array.data.len());
+        for (i, value) in array.data.into_iter().enumerate() {
+            result[i] = value;
+        }
+
+        Ok(result)
+    }
+
+    pub fn write_npy<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_array(writer, &format!("{},", self.size()), (0..self.size()).map(|i| self[i]))
+    }
+}
+
+/// Sizes and offsets in zip records without Zip64 extensions, where `u32::MAX` is reserved.
+fn zip_u32(value: usize) -> io::Result<u32> {
+    u32::try_from(value)
+        .ok()
+        .filter(|&value| value != u32::MAX)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "archive exceeds the 4 GiB zip limit"))
+}
+
+/// Builds an uncompressed `.npz` archive, as written by `numpy.savez`.
+pub struct NpzWriter<W: Write> {
+    writer: W,
+    offset: usize,
+    central_directory: Vec<u8>,
+    entries: u16,
+}
+
+impl<W: Write> NpzWriter<W> {
+    pub fn new(writer: W) -> Self {
+        NpzWriter {
+            writer,
+            offset: 0,
+            central_directory: Vec::new(),
+            entries: 0,
+        }
+    }
+
+    pub fn add_matrix<K: NpyElement>(&mut self, name: &str, matrix: &Matrix<K>) -> io::Result<()> {
+        let mut data = Vec::new();
+        matrix.write_npy(&mut data)?;
+        self.add_entry(name, &data)
+    }
+
+    pub fn add_vector<K: NpyElement>(&mut self, name: &str, vector: &Vector<K>) -> io::Result<()> {
+        let mut data = Vec::new();
+        vector.write_npy(&mut data)?;
+        self.add_entry(name, &data)
+    }
+
+    /// Writes the central directory and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let mut end = Vec::new();
+        end.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        end.extend_from_slice(&[0; 4]);
+        end.extend_from_slice(&self.entries.to_le_bytes());
+        end.extend_from_slice(&self.entries.to_le_bytes());
+        end.extend_from_slice(&zip_u32(self.central_directory.len())?.to_le_bytes());
+        end.extend_from_slice(&zip_u32(self.offset)?.to_le_bytes());
+        end.extend_from_slice(&[0; 2]);
+
+        self.writer.write_all(&self.central_directory)?;
+        self.writer.write_all(&end)?;
+        Ok(self.writer)
+    }
+
+    fn add_entry(&mut self, name: &str, data: &[u8]) -> io::Result<()> {
+        let name = format!("{name}.npy");
+        // 0xffff is reserved for Zip64 in the entry count
+        if self.entries >= u16::MAX - 1 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "too many archive entries"));
+        }
+        let name_len = u16::try_from(name.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "entry name too long"))?;
+        let (size, offset) = (zip_u32(data.len())?, zip_u32(self.offset)?);
+        let crc = crc32(data);
+        // Version 2.0, no flags, stored, 1980-01-01 00:00
+        let mut common = Vec::new();
+        common.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 0x21, 0]);
+        common.extend_from_slice(&crc.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&name_len.to_le_bytes());
+        common.extend_from_slice(&[0; 2]);
+
+        let mut local = Vec::new();
+        local.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        local.extend_from_slice(&common);
+        local.extend_from_slice(name.as_bytes());
+        self.writer.write_all(&local)?;
+        self.writer.write_all(data)?;
+
+        self.central_directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        self.central_directory.extend_from_slice(&[20, 0]);
+        self.central_directory.extend_from_slice(&common);
+        self.central_directory.extend_from_slice(&[0; 10]);
+        self.central_directory.extend_from_slice(&offset.to_le_bytes());
+        self.central_directory.extend_from_slice(name.as_bytes());
+
+        self.offset += local.len() + data.len();
+        self.entries += 1;
+        Ok(())
+    }
+}
+
+/// An `.npz` archive read into memory. Only stored (uncompressed) entries are supported.
+pub struct NpzArchive {
+    entries: Vec<(String, Vec<u8>)>,
+}
+
+fn le_u16(bytes: &[u8], at: usize) -> Option<usize> {
+    Some(u16::from_le_bytes(bytes.get(at..at.checked_add(2)?)?.try_into().ok()?) as usize)
+}
+
+fn le_u32(bytes: &[u8], at: usize) -> Option<usize> {
+    Some(u32::from_le_bytes(bytes.get(at..at.checked_add(4)?)?.try_into().ok()?) as usize)
+}
+
+fn le_u64(bytes: &[u8], at: usize) -> Option<usize> {
+    usize::try_from(u64::from_le_bytes(bytes.get(at..at.checked_add(8)?)?.try_into().ok()?)).ok()
+}
+
+impl NpzArchive {
+    pub fn read<R: Read>(mut reader: R) -> Result<NpzArchive, NpyError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        let end = (0..bytes.len().saturating_sub(21))
+            .rev()
+            .find(|&i| bytes[i..i + 4] == 0x0605_4b50u32.to_le_bytes())
+            .ok_or(NpyError::InvalidArchive("missing end of central directory"))?;
+        let count = le_u16(&bytes, end + 10).ok_or(NpyError::InvalidArchive("truncated"))?;
+        let mut at = le_u32(&bytes, end + 16).ok_or(NpyError::InvalidArchive("truncated"))?;
+
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            if le_u32(&bytes, at) != Some(0x0201_4b50) {
+                return Err(NpyError::InvalidArchive("bad central directory entry"));
+            }
+            let field = |offset: usize| le_u16(&bytes, at + offset).ok_or(NpyError::InvalidArchive("truncated"));
+            let wide = |offset: usize| le_u32(&bytes, at + offset).ok_or(NpyError::InvalidArchive("truncated"));
+            if field(10)? != 0 {
+                return Err(NpyError::InvalidArchive("compressed entries are not supported"));
+            }
+            let (name_len, extra_len, comment_len) = (field(28)?, field(30)?, field(32)?);
+            let mut size = wide(24)?;
+            let mut offset = wide(42)?;
+            let name = bytes.get(at + 46..at + 46 + name_len).ok_or(NpyError::InvalidArchive("truncated"))?;
+            let name = String::from_utf8_lossy(name).into_owned();
+
+            // Zip64 extra field holds, in order, whichever of the uncompressed size,
+            // compressed size and header offset overflowed 32 bits
+            let overflow = u32::MAX as usize;
+            let compressed_overflow = wide(20)? == overflow;
+            let mut extra = at + 46 + name_len;
+            let extra_end = extra + extra_len;
+            while extra + 4 <= extra_end {
+                let (id, len) = (field(extra - at)?, field(extra + 2 - at)?);
+                if id == 1 {
+                    let mut cursor = extra + 4;
+                    let mut next = || {
+                        cursor += 8;
+                        le_u64(&bytes, cursor - 8).ok_or(NpyError::InvalidArchive("truncated"))
+                    };
+                    if size == overflow {
+                        size = next()?;
+                    }
+                    if compressed_overflow {
+                        next()?;
+                    }
+                    if offset == overflow {
+                        offset = next()?;
+                    }
+                }
+                extra += 4 + len;
+            }
+
+            // Zip64 sizes and offsets are untrusted 64-bit values, so every sum is checked
+            let local_name_len = offset.checked_add(26).and_then(|at| le_u16(&bytes, at)).ok_or(NpyError::InvalidArchive("truncated"))?;
+            let local_extra_len = offset.checked_add(28).and_then(|at| le_u16(&bytes, at)).ok_or(NpyError::InvalidArchive("truncated"))?;
+            let data = offset
+                .checked_add(30 + local_name_len + local_extra_len)
+                .and_then(|start| bytes.get(start..start.checked_add(size)?))
+                .ok_or(NpyError::InvalidArchive("truncated"))?;
+            if crc32(data) != wide(16)? as u32 {
+                return Err(NpyError::InvalidArchive("checksum mismatch"));
+            }
+
+            let name = name.strip_suffix(".npy").map(str::to_string).unwrap_or(name);
+            entries.push((name, data.to_vec()));
+            at = extra_end + comment_len;
+        }
+
+        Ok(NpzArchive { entries })
+    }
+
+    /// Array names, without the `.npy` suffix.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn matrix<K: NpyElement>(&self, name: &str) -> Result<Matrix<K>, NpyError> {
+        Matrix::read_npy(self.entry(name)?)
+    }
+
+    pub fn vector<K: NpyElement>(&self, name: &str) -> Result<Vector<K>, NpyError> {
+        Vector::read_npy(self.entry(name)?)
+    }
+
+    fn entry(&self, name: &str) -> Result<&[u8], NpyError> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == name)
+            .map(|(_, data)| data.as_slice())
+            .ok_or_else(|| NpyError::MissingEntry(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn npy(header: &str, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    fn hex(text: &str) -> Vec<u8> {
+        (0..text.len()).step_by(2).map(|i| u8::from_str_radix(&text[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_matrix_npy_round_trip() {
+        let a = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let mut buffer = Vec::new();
+        a.write_npy(&mut buffer).unwrap();
+        assert_eq!((buffer.len() - 6 * 8) % 64, 0);
+        let header = std::str::from_utf8(&buffer[10..buffer.len() - 48]).unwrap();
+        assert!(header.starts_with("{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }"));
+        assert!(header.ends_with('\n'));
+        assert_eq!(Matrix::<f64>::read_npy(buffer.as_slice()).unwrap(), a);
+    }
+
+    #[test]
+    fn test_matrix_read_c_order() {
+        let payload: Vec<u8> = [1i32, 2, 3, 4, 5, 6].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let bytes = npy("{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }\n", &payload);
+        assert_eq!(Matrix::<i32>::read_npy(bytes.as_slice()).unwrap(), Matrix::from_rows([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]));
+    }
+
+    #[test]
+    fn test_vector_npy_round_trip() {
+        let v: Vector<f32> = Vector::from([1.5, -2., 3.25]);
+        let mut buffer = Vec::new();
+        v.write_npy(&mut buffer).unwrap();
+        assert!(std::str::from_utf8(&buffer[10..70]).unwrap().contains("'shape': (3,)"));
+        assert_eq!(Vector::<f32>::read_npy(buffer.as_slice()).unwrap(), v);
+        assert_eq!(Matrix::<f32>::read_npy(buffer.as_slice()).unwrap().shape(), (1, 3));
+    }
+
+    #[test]
+    fn test_npy_errors() {
+        assert!(matches!(Matrix::<f64>::read_npy(&b"NUMPY!!!!!"[..]), Err(NpyError::InvalidMagic)));
+
+        let bytes = npy("{'descr': '<f4', 'fortran_order': True, 'shape': (1,), }\n", &[0; 4]);
+        assert!(matches!(
+            Matrix::<f64>::read_npy(bytes.as_slice()),
+            Err(NpyError::DtypeMismatch { expected: "<f8", .. })
+        ));
+
+        let bytes = npy("{'descr': '<f8', 'fortran_order': True, 'shape': (2, 2, 2), }\n", &[0; 64]);
+        assert!(matches!(Matrix::<f64>::read_npy(bytes.as_slice()), Err(NpyError::UnsupportedShape(_))));
+
+        let bytes = npy("{'descr': '<f8', 'fortran_order': True, 'shape': (4294967296, 4294967296), }\n", &[]);
+        assert!(matches!(Matrix::<f64>::read_npy(bytes.as_slice()), Err(NpyError::UnsupportedShape(_))));
+
+        let bytes = npy("{'descr': '<f8', 'shape': (2,), }\n", &[0; 16]);
+        assert!(matches!(Vector::<f64>::read_npy(bytes.as_slice()), Err(NpyError::InvalidHeader(_))));
+
+        let bytes = npy("{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }\n", &[0; 12]);
+        assert!(matches!(Vector::<f64>::read_npy(bytes.as_slice()), Err(NpyError::Truncated)));
+    }
+
+    #[test]
+    fn test_npz_round_trip() {
+        let a = Matrix::from_rows([
+            [1., 2.],
+            [3., 4.],
+        ]);
+        let v = Vector::from([5i64, 6, 7]);
+        let mut writer = NpzWriter::new(Vec::new());
+        writer.add_matrix("a", &a).unwrap();
+        writer.add_vector("v", &v).unwrap();
+        let bytes = writer.finish().unwrap();
+
+        let archive = NpzArchive::read(bytes.as_slice()).unwrap();
+        assert_eq!(archive.names().collect::<Vec<_>>(), ["a", "v"]);
+        assert_eq!(archive.matrix::<f64>("a").unwrap(), a);
+        assert_eq!(archive.vector::<i64>("v").unwrap(), v);
+        assert!(matches!(archive.matrix::<f64>("b"), Err(NpyError::MissingEntry(_))));
+        assert!(matches!(archive.matrix::<f32>("a"), Err(NpyError::DtypeMismatch { .. })));
+    }
+
+    #[test]
+    fn test_npz_write_limits() {
+        assert_eq!(zip_u32(12).unwrap(), 12);
+        assert!(zip_u32(u32::MAX as usize).is_err());
+        assert!(zip_u32(u32::MAX as usize + 1).is_err());
+
+        let mut writer = NpzWriter::new(Vec::new());
+        writer.entries = u16::MAX - 1;
+        let error = writer.add_vector("v", &Vector::from([1.])).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(writer.writer.is_empty());
+    }
+
+    #[test]
+    fn test_npz_read_python_zip64() {
+        // Written by Python's zipfile with force_zip64=True, as numpy.savez does
+        let bytes = hex(concat!(
+            "504b03042d000000000000002100dca9cddaffffffffffffffff05001400762e6e70790100100082000000000000",
+            "008200000000000000934e554d5059010076007b276465736372273a20277c6931272c2027666f727472616e5f6f",
+            "72646572273a2046616c73652c20277368617065273a2028322c292c207d20202020202020202020202020202020",
+            "20202020202020202020202020202020202020202020202020202020202020202020202020202020202020200a07",
+            "f9504b01022d032d000000000000002100dca9cdda82000000820000000500000000000000000000008001000000",
+            "00762e6e7079504b0506000000000100010033000000b90000000000",
+        ));
+        let archive = NpzArchive::read(bytes.as_slice()).unwrap();
+        assert_eq!(archive.vector::<i8>("v").unwrap(), Vector::from([7i8, -7]));
+    }
+
+    #[test]
+    fn test_npz_corrupt() {
+        let mut writer = NpzWriter::new(Vec::new());
+        writer.add_vector("v", &Vector::from([1., 2.])).unwrap();
+        let mut bytes = writer.finish().unwrap();
+        bytes[100] ^= 0xFF;
+        assert!(matches!(NpzArchive::read(bytes.as_slice()), Err(NpyError::InvalidArchive("checksum mismatch"))));
+        assert!(matches!(NpzArchive::read(&b"not a zip"[..]), Err(NpyError::InvalidArchive(_))));
+
+        // Zip64 extra fields claiming a size, and then also an offset, of u64::MAX
+        let mut writer = NpzWriter::new(Vec::new());
+        writer.add_vector("v", &Vector::from([1., 2.])).unwrap();
+        let bytes = writer.finish().unwrap();
+        let directory = le_u32(&bytes, bytes.len() - 22 + 16).unwrap();
+        let name_end = directory + 46 + le_u16(&bytes, directory + 28).unwrap();
+        for fields in [1, 2] {
+            let mut corrupt = bytes.clone();
+            corrupt[directory + 24..directory + 28].copy_from_slice(&u32::MAX.to_le_bytes());
+            if fields == 2 {
+                corrupt[directory + 42..directory + 46].copy_from_slice(&u32::MAX.to_le_bytes());
+            }
+            let mut extra = [1u16.to_le_bytes(), (8 * fields as u16).to_le_bytes()].concat();
+            for _ in 0..fields {
+                extra.extend_from_slice(&u64::MAX.to_le_bytes());
+            }
+            corrupt[directory + 30..directory + 32].copy_from_slice(&(extra.len() as u16).to_le_bytes());
+            corrupt.splice(name_end..name_end, extra);
+            assert!(matches!(NpzArchive::read(corrupt.as_slice()), Err(NpyError::InvalidArchive("truncated"))));
+        }
+    }
+}
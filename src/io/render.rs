@@ -0,0 +1,166 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::Field;
+
+/// The amsmath environment used by `to_latex`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatexDelimiter {
+    /// `pmatrix`, parentheses
+    Parentheses,
+    /// `bmatrix`, square brackets
+    Brackets,
+    /// `vmatrix`, vertical bars, as for a determinant
+    Bars,
+}
+
+impl LatexDelimiter {
+    pub fn environment(&self) -> &'static str {
+        match self {
+            LatexDelimiter::Parentheses => "pmatrix",
+            LatexDelimiter::Brackets => "bmatrix",
+            LatexDelimiter::Bars => "vmatrix",
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Formatted elements, row by row.
+fn formatted_rows<K: Field, F: FnMut(K) -> String>(matrix: &Matrix<K>, mut format: F) -> Vec<Vec<String>> {
+    let (width, height) = matrix.shape();
+    (0..height).map(|y| (0..width).map(|x| format(matrix[(x, y)])).collect()).collect()
+}
+
+impl<K: Field> Matrix<K> {
+    /// Renders an amsmath matrix, e.g. `to_latex(LatexDelimiter::Brackets, |v| format!("{v:.2}"))`.
+    pub fn to_latex<F: FnMut(K) -> String>(&self, delimiter: LatexDelimiter, format: F) -> String {
+        let rows: Vec<String> = formatted_rows(self, format).iter().map(|row| row.join(" & ")).collect();
+        let environment = delimiter.environment();
+
+        format!("\\begin{{{environment}}}\n{}\n\\end{{{environment}}}", rows.join(" \\\\\n"))
+    }
+
+    /// Renders a GitHub-flavored Markdown table with an empty header row and right-aligned columns.
+    pub fn to_markdown_table<F: FnMut(K) -> String>(&self, format: F) -> String {
+        let width = self.shape().0;
+        let mut lines = vec![format!("|{}", "   |".repeat(width)), format!("|{}", "--:|".repeat(width))];
+
+        for row in formatted_rows(self, format) {
+            // A literal pipe would end the cell early
+            let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+            lines.push(format!("| {} |", cells.join(" | ")));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Renders an HTML `<table>`, escaping the formatted elements.
+    pub fn to_html_table<F: FnMut(K) -> String>(&self, format: F) -> String {
+        let mut html = String::from("<table>\n");
+
+        for row in formatted_rows(self, format) {
+            html.push_str("  <tr>");
+            for cell in row {
+                html.push_str(&format!("<td>{}</td>", escape_html(&cell)));
+            }
+            html.push_str("</tr>\n");
+        }
+
+        html.push_str("</table>");
+        html
+    }
+}
+
+/// Vectors render as a single column, as in `Display`.
+impl<K: Field> Vector<K> {
+    fn to_column(&self) -> Matrix<K> {
+        Matrix::from_fn(1, self.size(), |_, y| self[y])
+    }
+
+    pub fn to_latex<F: FnMut(K) -> String>(&self, delimiter: LatexDelimiter, format: F) -> String {
+        self.to_column().to_latex(delimiter, format)
+    }
+
+    pub fn to_markdown_table<F: FnMut(K) -> String>(&self, format: F) -> String {
+        self.to_column().to_markdown_table(format)
+    }
+
+    pub fn to_html_table<F: FnMut(K) -> String>(&self, format: F) -> String {
+        self.to_column().to_html_table(format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_latex() {
+        let a = Matrix::from_rows([
+            [1., 2.5],
+            [-3., 4.],
+        ]);
+        assert_eq!(
+            a.to_latex(LatexDelimiter::Brackets, |v| v.to_string()),
+            "\\begin{bmatrix}\n1 & 2.5 \\\\\n-3 & 4\n\\end{bmatrix}"
+        );
+        assert_eq!(
+            a.to_latex(LatexDelimiter::Bars, |v| format!("{v:.1}")),
+            "\\begin{vmatrix}\n1.0 & 2.5 \\\\\n-3.0 & 4.0\n\\end{vmatrix}"
+        );
+
+        let v = Vector::from([1, 2]);
+        assert_eq!(
+            v.to_latex(LatexDelimiter::Parentheses, |v| v.to_string()),
+            "\\begin{pmatrix}\n1 \\\\\n2\n\\end{pmatrix}"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_table() {
+        let a = Matrix::from_rows([
+            [1, 20, 3],
+            [4, 5, -6],
+        ]);
+        assert_eq!(
+            a.to_markdown_table(|v| v.to_string()),
+            "|   |   |   |\n|--:|--:|--:|\n| 1 | 20 | 3 |\n| 4 | 5 | -6 |"
+        );
+        assert_eq!(
+            Matrix::from_rows([[1]]).to_markdown_table(|v| format!("|{v}|")),
+            "|   |\n|--:|\n| \\|1\\| |"
+        );
+        assert_eq!(Vector::from([7, 8]).to_markdown_table(|v| v.to_string()), "|   |\n|--:|\n| 7 |\n| 8 |");
+    }
+
+    #[test]
+    fn test_to_html_table() {
+        let a = Matrix::from_rows([
+            [1., 2.],
+            [3., 4.],
+        ]);
+        assert_eq!(
+            a.to_html_table(|v| format!("{v:.1}")),
+            "<table>\n  <tr><td>1.0</td><td>2.0</td></tr>\n  <tr><td>3.0</td><td>4.0</td></tr>\n</table>"
+        );
+        assert_eq!(
+            Vector::from([1]).to_html_table(|v| format!("<{v}&>")),
+            "<table>\n  <tr><td>&lt;1&amp;&gt;</td></tr>\n</table>"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+#![allow(dead_code)]
+
+use crate::core::Vector;
+use crate::iterative::{IdentityPreconditioner, IterativeSolution, Preconditioner};
+use crate::operator::LinearOperator;
+use crate::traits::Field;
+
+/// Stabilized biconjugate gradient for general square systems, starting from x = 0.
+/// Stops once ‖b - Ax‖ <= tolerance·‖b‖, or on breakdown.
+pub fn bicgstab<K: Field, A: LinearOperator<K> + ?Sized>(a: &A, b: &Vector<K>, tolerance: K, max_iterations: usize) -> IterativeSolution<K> {
+    preconditioned_bicgstab(a, &IdentityPreconditioner, b, tolerance, max_iterations)
+}
+
+/// BiCGSTAB with right preconditioning by `m`, so `history` still tracks ‖b - Ax‖.
+pub fn preconditioned_bicgstab<K, A, P>(a: &A, m: &P, b: &Vector<K>, tolerance: K, max_iterations: usize) -> IterativeSolution<K>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+    P: Preconditioner<K> + ?Sized,
+{
+    assert!(a.is_square(), "BiCGSTAB requires a square matrix");
+    assert_eq!(a.shape().0, b.size(), "Vector size must equal matrix width");
+
+    let threshold = tolerance * b.norm();
+    let mut x = Vector::from_elem(K::zero(), b.size());
+    let mut r = b.clone();
+    let r_hat = r.clone();
+    let mut p = Vector::from_elem(K::zero(), b.size());
+    let mut v = Vector::from_elem(K::zero(), b.size());
+    let (mut rho, mut alpha, mut omega) = (K::one(), K::one(), K::one());
+    let mut history = vec![r.norm()];
+    let mut converged = history[0] <= threshold;
+    let mut iterations = 0;
+
+    while !converged && iterations < max_iterations {
+        let rho_new = r_hat.dot(&r);
+        if rho_new == K::zero() || omega == K::zero() {
+            break;
+        }
+
+        let beta = (rho_new / rho) * (alpha / omega);
+        p = r.clone() + (p - v * omega) * beta;
+        let p_hat = m.solve(&p);
+        v = a.apply(&p_hat);
+        let denominator = r_hat.dot(&v);
+        if denominator == K::zero() {
+            break;
+        }
+        alpha = rho_new / denominator;
+        let s = r - v.clone() * alpha;
+        iterations += 1;
+
+        if s.norm() <= threshold {
+            x = x + p_hat * alpha;
+            history.push(s.norm());
+            converged = true;
+            break;
+        }
+
+        let s_hat = m.solve(&s);
+        let t = a.apply(&s_hat);
+        let tt = t.dot(&t);
+        omega = if tt == K::zero() { K::zero() } else { t.dot(&s) / tt };
+        x = x + p_hat * alpha + s_hat * omega;
+        r = s - t * omega;
+        rho = rho_new;
+
+        history.push(r.norm());
+        converged = history[iterations] <= threshold;
+    }
+
+    IterativeSolution { x, iterations, converged, history }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Matrix;
+    use crate::iterative::{Ilu0, convection_diffusion};
+
+    #[test]
+    fn test_bicgstab() {
+        let a = Matrix::from_rows([
+            [4., 1., 0.],
+            [2., 5., 1.],
+            [0., -1., 3.],
+        ]);
+        let b = Vector::from([1., 2., 3.]);
+        let solution = bicgstab(&a, &b, 1e-12, 50);
+        assert!(solution.converged);
+        assert!((b.clone() - &a * &solution.x).norm() <= 1e-12 * b.norm() * 1.01);
+        assert_eq!(solution.history.len(), solution.iterations + 1);
+    }
+
+    #[test]
+    fn test_bicgstab_convection_diffusion() {
+        let a = convection_diffusion(40);
+        let b = Vector::from_elem(1., 40);
+        let solution = bicgstab(&a, &b, 1e-10, 200);
+        assert!(solution.converged);
+        assert!((b.clone() - &a * &solution.x).norm() <= 1e-10 * b.norm() * 1.01);
+    }
+
+    #[test]
+    fn test_preconditioned_bicgstab() {
+        let a = convection_diffusion(40);
+        let b = Vector::from_elem(1., 40);
+        let m = Ilu0::new(&a).unwrap();
+        let solution = preconditioned_bicgstab(&a, &m, &b, 1e-10, 200);
+        assert!(solution.converged);
+        assert_eq!(solution.iterations, 1);
+        assert!((b.clone() - &a * &solution.x).norm() <= 1e-10 * b.norm());
+    }
+
+    #[test]
+    fn test_bicgstab_iteration_limit() {
+        let a = convection_diffusion(40);
+        let b = Vector::from_elem(1., 40);
+        let solution = bicgstab(&a, &b, 1e-14, 2);
+        assert!(!solution.converged);
+        assert_eq!(solution.iterations, 2);
+    }
+}
@@ -0,0 +1,154 @@
+#![allow(dead_code)]
+
+use crate::core::Vector;
+use crate::iterative::{IdentityPreconditioner, IterativeSolution, Preconditioner};
+use crate::operator::LinearOperator;
+use crate::traits::Field;
+
+/// Conjugate gradient for symmetric positive-definite systems, starting from x = 0.
+/// Stops once ‖b - Ax‖ <= tolerance·‖b‖.
+pub fn conjugate_gradient<K: Field, A: LinearOperator<K> + ?Sized>(a: &A, b: &Vector<K>, tolerance: K, max_iterations: usize) -> IterativeSolution<K> {
+    preconditioned_conjugate_gradient(a, &IdentityPreconditioner, b, tolerance, max_iterations)
+}
+
+/// Conjugate gradient with a symmetric positive-definite preconditioner `m`.
+pub fn preconditioned_conjugate_gradient<K, A, P>(a: &A, m: &P, b: &Vector<K>, tolerance: K, max_iterations: usize) -> IterativeSolution<K>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+    P: Preconditioner<K> + ?Sized,
+{
+    assert!(a.is_square(), "Conjugate gradient requires a square matrix");
+    assert_eq!(a.shape().0, b.size(), "Vector size must equal matrix width");
+
+    let threshold = tolerance * b.norm();
+    let mut x = Vector::from_elem(K::zero(), b.size());
+    let mut r = b.clone();
+    let mut p = m.solve(&r);
+    let mut rz = r.dot(&p);
+    let mut history = vec![r.norm()];
+    let mut converged = history[0] <= threshold;
+    let mut iterations = 0;
+
+    while !converged && iterations < max_iterations {
+        let ap = a.apply(&p);
+        let curvature = p.dot(&ap);
+        if curvature == K::zero() {
+            break;
+        }
+
+        let alpha = rz / curvature;
+        x = x + p.clone() * alpha;
+        r = r - ap * alpha;
+
+        iterations += 1;
+        history.push(r.norm());
+        converged = history[iterations] <= threshold;
+
+        let z = m.solve(&r);
+        let rz_new = r.dot(&z);
+        p = z + p * (rz_new / rz);
+        rz = rz_new;
+    }
+
+    IterativeSolution { x, iterations, converged, history }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Matrix;
+    use crate::iterative::IncompleteCholesky;
+    use crate::operator::FnOperator;
+
+    fn poisson(size: usize) -> Matrix<f64> {
+        let mut a = Matrix::from_elem(0., size, size);
+        for i in 0..size {
+            a[(i, i)] = 2.;
+            if i > 0 {
+                a[(i - 1, i)] = -1.;
+                a[(i, i - 1)] = -1.;
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn test_conjugate_gradient() {
+        let a = Matrix::from_rows([
+            [4., 1.],
+            [1., 3.],
+        ]);
+        let b: Vector<f64> = Vector::from([1., 2.]);
+        let solution = conjugate_gradient(&a, &b, 1e-12, 10);
+        assert!(solution.converged);
+        assert!(solution.iterations <= 2);
+        assert!((solution.x[0] - 1. / 11.).abs() < 1e-12);
+        assert!((solution.x[1] - 7. / 11.).abs() < 1e-12);
+        assert_eq!(solution.history.len(), solution.iterations + 1);
+    }
+
+    #[test]
+    fn test_conjugate_gradient_poisson() {
+        let a = poisson(50);
+        let b = Vector::from_elem(1., 50);
+        let solution = conjugate_gradient(&a, &b, 1e-10, 100);
+        assert!(solution.converged);
+        assert!((b.clone() - &a * &solution.x).norm() <= 1e-10 * b.norm() * 1.01);
+    }
+
+    #[test]
+    fn test_conjugate_gradient_matrix_free() {
+        let size = 50;
+        let a = FnOperator::new(size, size, move |x: &Vector<f64>| {
+            let mut result = Vector::from_elem(0., size);
+            for i in 0..size {
+                result[i] = 2. * x[i];
+                if i > 0 {
+                    result[i] -= x[i - 1];
+                }
+                if i + 1 < size {
+                    result[i] -= x[i + 1];
+                }
+            }
+            result
+        });
+        let b = Vector::from_elem(1., size);
+        let solution = conjugate_gradient(&a, &b, 1e-10, 100);
+        let reference = conjugate_gradient(&poisson(size), &b, 1e-10, 100);
+        assert!(solution.converged);
+        assert_eq!(solution.iterations, reference.iterations);
+        assert!((solution.x - reference.x).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_conjugate_gradient_iteration_limit() {
+        let a = poisson(50);
+        let b = Vector::from_elem(1., 50);
+        let solution = conjugate_gradient(&a, &b, 1e-10, 3);
+        assert!(!solution.converged);
+        assert_eq!(solution.iterations, 3);
+        assert_eq!(solution.history.len(), 4);
+    }
+
+    #[test]
+    fn test_preconditioned_conjugate_gradient() {
+        let a = poisson(50);
+        let b = Vector::from_elem(1., 50);
+        let m = IncompleteCholesky::new(&a).unwrap();
+        let solution = preconditioned_conjugate_gradient(&a, &m, &b, 1e-10, 100);
+        assert!(solution.converged);
+        assert_eq!(solution.iterations, 1);
+        assert!((b.clone() - &a * &solution.x).norm() <= 1e-10 * b.norm());
+    }
+
+    #[test]
+    fn test_conjugate_gradient_zero_rhs() {
+        let a = poisson(5);
+        let b = Vector::from_elem(0., 5);
+        let solution = conjugate_gradient(&a, &b, 1e-10, 10);
+        assert!(solution.converged);
+        assert_eq!(solution.iterations, 0);
+        assert_eq!(solution.x, b);
+    }
+}
@@ -0,0 +1,192 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::iterative::{IdentityPreconditioner, IterativeSolution, Preconditioner};
+use crate::operator::LinearOperator;
+use crate::traits::{Abs, Field, Sqrt};
+
+/// Restarted GMRES(m) for general square systems, starting from x = 0.
+/// The Krylov basis is rebuilt every `restart` iterations; stops once
+/// ‖b - Ax‖ <= tolerance·‖b‖.
+pub fn gmres<K: Field, A: LinearOperator<K> + ?Sized>(a: &A, b: &Vector<K>, restart: usize, tolerance: K, max_iterations: usize) -> IterativeSolution<K> {
+    preconditioned_gmres(a, &IdentityPreconditioner, b, restart, tolerance, max_iterations)
+}
+
+/// GMRES(m) with right preconditioning by `m`, so `history` still tracks ‖b - Ax‖.
+pub fn preconditioned_gmres<K, A, P>(a: &A, m: &P, b: &Vector<K>, restart: usize, tolerance: K, max_iterations: usize) -> IterativeSolution<K>
+where
+    K: Field,
+    A: LinearOperator<K> + ?Sized,
+    P: Preconditioner<K> + ?Sized,
+{
+    assert!(a.is_square(), "GMRES requires a square matrix");
+    assert_eq!(a.shape().0, b.size(), "Vector size must equal matrix width");
+    assert!(restart > 0, "GMRES restart length must be positive");
+
+    let threshold = tolerance * b.norm();
+    let mut x = Vector::from_elem(K::zero(), b.size());
+    let mut history = vec![b.norm()];
+    let mut converged = history[0] <= threshold;
+    let mut iterations = 0;
+    // A singular Hessenberg system: restarting would only repeat the same Krylov space
+    let mut breakdown = false;
+
+    while !converged && !breakdown && iterations < max_iterations {
+        let r = b.clone() - a.apply(&x);
+        let beta = r.norm();
+        if beta <= threshold {
+            converged = true;
+            break;
+        }
+
+        // Hessenberg matrix H is (restart + 1) rows by restart columns
+        let mut h = Matrix::from_elem(K::zero(), restart, restart + 1);
+        let mut basis = vec![r * (K::one() / beta)];
+        let mut cosines = Vec::with_capacity(restart);
+        let mut sines = Vec::with_capacity(restart);
+        let mut g = Vector::from_elem(K::zero(), restart + 1);
+        g[0] = beta;
+        let mut steps = 0;
+
+        while steps < restart && iterations < max_iterations {
+            let j = steps;
+
+            // Arnoldi step with modified Gram-Schmidt
+            let mut w = a.apply(&m.solve(&basis[j]));
+            for (i, v) in basis.iter().enumerate() {
+                h[(j, i)] = w.dot(v);
+                w = w - v.clone() * h[(j, i)];
+            }
+            let w_norm = w.norm();
+            h[(j, j + 1)] = w_norm;
+
+            // Apply previous Givens rotations to the new column, then eliminate H[j+1][j]
+            for i in 0..j {
+                let (c, s): (K, K) = (cosines[i], sines[i]);
+                let temp = c * h[(j, i)] + s * h[(j, i + 1)];
+                h[(j, i + 1)] = c * h[(j, i + 1)] - s * h[(j, i)];
+                h[(j, i)] = temp;
+            }
+            let denominator = Sqrt::sqrt(h[(j, j)] * h[(j, j)] + h[(j, j + 1)] * h[(j, j + 1)]);
+            if denominator == K::zero() {
+                breakdown = true;
+                break;
+            }
+            let (c, s) = (h[(j, j)] / denominator, h[(j, j + 1)] / denominator);
+            cosines.push(c);
+            sines.push(s);
+            h[(j, j)] = denominator;
+            h[(j, j + 1)] = K::zero();
+            g[j + 1] = -s * g[j];
+            g[j] = c * g[j];
+
+            steps += 1;
+            iterations += 1;
+            history.push(Abs::abs(g[j + 1]));
+            if history[iterations] <= threshold || w_norm == K::zero() {
+                break;
+            }
+            basis.push(w * (K::one() / w_norm));
+        }
+
+        // Back substitution on the triangularized Hessenberg system
+        let mut y = Vector::from_elem(K::zero(), steps);
+        let solvable = (0..steps).all(|i| h[(i, i)] != K::zero());
+        if solvable {
+            for i in (0..steps).rev() {
+                let mut value = g[i];
+                for k in (i + 1)..steps {
+                    value = value - h[(k, i)] * y[k];
+                }
+                y[i] = value / h[(i, i)];
+            }
+            let mut update = Vector::from_elem(K::zero(), b.size());
+            for i in 0..steps {
+                update = update + basis[i].clone() * y[i];
+            }
+            x = x + m.solve(&update);
+        } else {
+            breakdown = true;
+        }
+
+        converged = history[iterations] <= threshold;
+    }
+
+    IterativeSolution { x, iterations, converged, history }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iterative::{Ilu0, convection_diffusion};
+
+    #[test]
+    fn test_gmres() {
+        let a = Matrix::from_rows([
+            [4., 1., 0.],
+            [2., 5., 1.],
+            [0., -1., 3.],
+        ]);
+        let b = Vector::from([1., 2., 3.]);
+        let solution = gmres(&a, &b, 3, 1e-12, 10);
+        assert!(solution.converged);
+        assert!(solution.iterations <= 3);
+        assert!((b.clone() - &a * &solution.x).norm() <= 1e-12 * b.norm() * 1.01);
+        assert_eq!(solution.history.len(), solution.iterations + 1);
+    }
+
+    #[test]
+    fn test_gmres_restarted() {
+        let a = convection_diffusion(40);
+        let b = Vector::from_elem(1., 40);
+        let solution = gmres(&a, &b, 5, 1e-10, 500);
+        assert!(solution.converged);
+        assert!((b.clone() - &a * &solution.x).norm() <= 1e-9 * b.norm());
+        assert!(solution.history.windows(2).all(|w| w[1] <= w[0] * (1. + 1e-12)));
+    }
+
+    #[test]
+    fn test_preconditioned_gmres() {
+        let a = convection_diffusion(40);
+        let b = Vector::from_elem(1., 40);
+        let m = Ilu0::new(&a).unwrap();
+        let solution = preconditioned_gmres(&a, &m, &b, 5, 1e-10, 500);
+        assert!(solution.converged);
+        assert_eq!(solution.iterations, 1);
+        assert!((b.clone() - &a * &solution.x).norm() <= 1e-10 * b.norm());
+    }
+
+    #[test]
+    fn test_gmres_singular() {
+        // A b = 0 on the first step, so the Givens rotation has nothing to eliminate
+        let a = Matrix::from_rows([
+            [1., 0.],
+            [0., 0.],
+        ]);
+        let b = Vector::from([0., 1.]);
+        let solution = gmres(&a, &b, 5, 1e-10, 20);
+        assert!(!solution.converged);
+        assert_eq!(solution.x, Vector::from([0., 0.]));
+        assert_eq!(solution.history.len(), solution.iterations + 1);
+
+        let a = Matrix::from_rows([
+            [1., 0., 0.],
+            [0., 0., 1.],
+            [0., 0., 0.],
+        ]);
+        let b = Vector::from([1., 0., 1.]);
+        let solution = gmres(&a, &b, 5, 1e-10, 20);
+        assert!(!solution.converged);
+        assert!(solution.x.all(|value: f64| value.is_finite()));
+    }
+
+    #[test]
+    fn test_gmres_iteration_limit() {
+        let a = convection_diffusion(40);
+        let b = Vector::from_elem(1., 40);
+        let solution = gmres(&a, &b, 5, 1e-14, 7);
+        assert!(!solution.converged);
+        assert_eq!(solution.iterations, 7);
+        assert_eq!(solution.history.len(), 8);
+    }
+}
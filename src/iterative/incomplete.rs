@@ -0,0 +1,198 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::iterative::Preconditioner;
+use crate::traits::{Field, Sqrt};
+
+/// Zero fill-in incomplete Cholesky, M = LLᵀ with L restricted to the pattern of tril(A).
+pub struct IncompleteCholesky<K: Field> {
+    l: Matrix<K>,
+}
+
+impl<K: Field> IncompleteCholesky<K> {
+    /// Returns `None` when factorization breaks down on a non-positive pivot.
+    pub fn new(a: &Matrix<K>) -> Option<Self> {
+        assert!(a.is_square(), "Incomplete Cholesky requires a square matrix");
+        let size = a.shape().0;
+        let mut l = Matrix::from_elem(K::zero(), size, size);
+
+        for col in 0..size {
+            for row in col..size {
+                l[(col, row)] = a[(col, row)];
+            }
+        }
+
+        for k in 0..size {
+            if l[(k, k)] <= K::zero() {
+                return None;
+            }
+            l[(k, k)] = Sqrt::sqrt(l[(k, k)]);
+
+            for row in (k + 1)..size {
+                if l[(k, row)] != K::zero() {
+                    l[(k, row)] = l[(k, row)] / l[(k, k)];
+                }
+            }
+            for col in (k + 1)..size {
+                for row in col..size {
+                    if a[(col, row)] != K::zero() {
+                        l[(col, row)] = l[(col, row)] - l[(k, row)] * l[(k, col)];
+                    }
+                }
+            }
+        }
+
+        Some(IncompleteCholesky { l })
+    }
+}
+
+impl<K: Field> Preconditioner<K> for IncompleteCholesky<K> {
+    fn solve(&self, r: &Vector<K>) -> Vector<K> {
+        let size = self.l.shape().0;
+        assert_eq!(r.size(), size, "Vector size must equal preconditioner size");
+        let mut z = r.clone();
+
+        // L y = r
+        for row in 0..size {
+            for col in 0..row {
+                z[row] = z[row] - self.l[(col, row)] * z[col];
+            }
+            z[row] = z[row] / self.l[(row, row)];
+        }
+        // Lᵀ z = y
+        for row in (0..size).rev() {
+            for col in (row + 1)..size {
+                z[row] = z[row] - self.l[(row, col)] * z[col];
+            }
+            z[row] = z[row] / self.l[(row, row)];
+        }
+
+        z
+    }
+}
+
+/// Zero fill-in incomplete LU, M = LU with L and U restricted to the pattern of A.
+pub struct Ilu0<K: Field> {
+    lu: Matrix<K>,
+}
+
+impl<K: Field> Ilu0<K> {
+    /// Returns `None` when factorization meets a zero pivot.
+    pub fn new(a: &Matrix<K>) -> Option<Self> {
+        assert!(a.is_square(), "ILU(0) requires a square matrix");
+        let size = a.shape().0;
+        let mut lu = a.clone();
+
+        for row in 0..size {
+            for k in 0..row {
+                if a[(k, row)] == K::zero() {
+                    continue;
+                }
+                if lu[(k, k)] == K::zero() {
+                    return None;
+                }
+                lu[(k, row)] = lu[(k, row)] / lu[(k, k)];
+                for col in (k + 1)..size {
+                    if a[(col, row)] != K::zero() {
+                        lu[(col, row)] = lu[(col, row)] - lu[(k, row)] * lu[(col, k)];
+                    }
+                }
+            }
+            if lu[(row, row)] == K::zero() {
+                return None;
+            }
+        }
+
+        Some(Ilu0 { lu })
+    }
+}
+
+impl<K: Field> Preconditioner<K> for Ilu0<K> {
+    fn solve(&self, r: &Vector<K>) -> Vector<K> {
+        let size = self.lu.shape().0;
+        assert_eq!(r.size(), size, "Vector size must equal preconditioner size");
+        let mut z = r.clone();
+
+        // L y = r, unit diagonal
+        for row in 0..size {
+            for col in 0..row {
+                z[row] = z[row] - self.lu[(col, row)] * z[col];
+            }
+        }
+        // U z = y
+        for row in (0..size).rev() {
+            for col in (row + 1)..size {
+                z[row] = z[row] - self.lu[(col, row)] * z[col];
+            }
+            z[row] = z[row] / self.lu[(row, row)];
+        }
+
+        z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_incomplete_cholesky_exact_on_tridiagonal() {
+        let a = Matrix::from_rows([
+            [4., -1., 0.],
+            [-1., 4., -1.],
+            [0., -1., 4.],
+        ]);
+        let m = IncompleteCholesky::new(&a).unwrap();
+        let b = Vector::from([1., 2., 3.]);
+        assert!((&a * &m.solve(&b) - b).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_incomplete_cholesky_drops_fill() {
+        let a = Matrix::from_rows([
+            [4., -1., 0., -1.],
+            [-1., 4., -1., 0.],
+            [0., -1., 4., -1.],
+            [-1., 0., -1., 4.],
+        ]);
+        let m = IncompleteCholesky::new(&a).unwrap();
+        assert_eq!(m.l[(1, 3)], 0.);
+        assert_eq!(m.l[(0, 2)], 0.);
+
+        let a = Matrix::from_rows([
+            [1., 2.],
+            [2., 1.],
+        ]);
+        assert!(IncompleteCholesky::new(&a).is_none());
+    }
+
+    #[test]
+    fn test_ilu0_exact_on_tridiagonal() {
+        let a = Matrix::from_rows([
+            [4., -2., 0.],
+            [-1., 4., -3.],
+            [0., -1., 4.],
+        ]);
+        let m = Ilu0::new(&a).unwrap();
+        let b = Vector::from([1., 2., 3.]);
+        assert!((&a * &m.solve(&b) - b).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_ilu0_keeps_pattern() {
+        let a = Matrix::from_rows([
+            [4., 0., 1.],
+            [1., 4., 0.],
+            [0., 1., 4.],
+        ]);
+        let m = Ilu0::new(&a).unwrap();
+        assert_eq!(m.lu[(1, 0)], 0.);
+        assert_eq!(m.lu[(2, 1)], 0.);
+
+        let a = Matrix::from_rows([
+            [0., 1.],
+            [1., 0.],
+        ]);
+        assert!(Ilu0::new(&a).is_none());
+    }
+}
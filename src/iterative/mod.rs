@@ -0,0 +1,34 @@
+mod bicgstab;
+mod cg;
+mod gmres;
+mod incomplete;
+mod preconditioner;
+mod solution;
+mod stationary;
+
+#[allow(unused_imports)]
+pub use bicgstab::*;
+#[allow(unused_imports)]
+pub use cg::*;
+#[allow(unused_imports)]
+pub use gmres::*;
+#[allow(unused_imports)]
+pub use incomplete::*;
+pub use preconditioner::*;
+pub use solution::*;
+#[allow(unused_imports)]
+pub use stationary::*;
+
+/// Nonsymmetric, diagonally dominant test matrix shared by the Krylov solver tests.
+#[cfg(test)]
+pub(crate) fn convection_diffusion(size: usize) -> crate::core::Matrix<f64> {
+    let mut a = crate::core::Matrix::from_elem(0., size, size);
+    for i in 0..size {
+        a[(i, i)] = 3.;
+        if i > 0 {
+            a[(i - 1, i)] = -1.5;
+            a[(i, i - 1)] = -0.5;
+        }
+    }
+    a
+}
@@ -0,0 +1,87 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::traits::Field;
+
+/// Approximation M of a system matrix A; `solve` returns M⁻¹r.
+pub trait Preconditioner<K: Field> {
+    fn solve(&self, r: &Vector<K>) -> Vector<K>;
+}
+
+impl<K: Field, P: Preconditioner<K> + ?Sized> Preconditioner<K> for &P {
+    fn solve(&self, r: &Vector<K>) -> Vector<K> {
+        (**self).solve(r)
+    }
+}
+
+/// M = I, turning preconditioned solvers back into their plain versions.
+pub struct IdentityPreconditioner;
+
+impl<K: Field> Preconditioner<K> for IdentityPreconditioner {
+    fn solve(&self, r: &Vector<K>) -> Vector<K> {
+        r.clone()
+    }
+}
+
+/// M = diag(A).
+pub struct JacobiPreconditioner<K: Field> {
+    inverse_diagonal: Vector<K>,
+}
+
+impl<K: Field> JacobiPreconditioner<K> {
+    /// Returns `None` when the diagonal holds a zero.
+    pub fn new(a: &Matrix<K>) -> Option<Self> {
+        assert!(a.is_square(), "Jacobi preconditioner requires a square matrix");
+        let size = a.shape().0;
+        let mut inverse_diagonal = Vector::from_elem(K::zero(), size);
+
+        for i in 0..size {
+            if a[(i, i)] == K::zero() {
+                return None;
+            }
+            inverse_diagonal[i] = K::one() / a[(i, i)];
+        }
+
+        Some(JacobiPreconditioner { inverse_diagonal })
+    }
+}
+
+impl<K: Field> Preconditioner<K> for JacobiPreconditioner<K> {
+    fn solve(&self, r: &Vector<K>) -> Vector<K> {
+        assert_eq!(r.size(), self.inverse_diagonal.size(), "Vector size must equal preconditioner size");
+        let mut result = r.clone();
+
+        for i in 0..r.size() {
+            result[i] = result[i] * self.inverse_diagonal[i];
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_preconditioner() {
+        let r = Vector::from([1., -2., 3.]);
+        assert_eq!(IdentityPreconditioner.solve(&r), r);
+    }
+
+    #[test]
+    fn test_jacobi_preconditioner() {
+        let a = Matrix::from_rows([
+            [2., 1.],
+            [1., 4.],
+        ]);
+        let m = JacobiPreconditioner::new(&a).unwrap();
+        assert_eq!(m.solve(&Vector::from([1., 2.])), Vector::from([0.5, 0.5]));
+
+        let a = Matrix::from_rows([
+            [0., 1.],
+            [1., 4.],
+        ]);
+        assert!(JacobiPreconditioner::new(&a).is_none());
+    }
+}
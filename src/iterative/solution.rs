@@ -0,0 +1,19 @@
+#![allow(dead_code)]
+
+use crate::core::Vector;
+use crate::traits::Field;
+
+#[derive(Debug)]
+pub struct IterativeSolution<K: Field> {
+    pub x: Vector<K>,
+    pub iterations: usize,
+    pub converged: bool,
+    /// Residual norm ‖b - Ax‖ before the first iteration and after each one.
+    pub history: Vec<K>,
+}
+
+impl<K: Field> IterativeSolution<K> {
+    pub fn residual(&self) -> K {
+        *self.history.last().expect("history always holds the initial residual")
+    }
+}
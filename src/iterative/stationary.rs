@@ -0,0 +1,126 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::iterative::IterativeSolution;
+use crate::traits::Field;
+
+/// Jacobi iteration, starting from x = 0. Converges for strictly diagonally dominant A.
+/// Stops once ‖b - Ax‖ <= tolerance·‖b‖.
+pub fn jacobi<K: Field>(a: &Matrix<K>, b: &Vector<K>, tolerance: K, max_iterations: usize) -> IterativeSolution<K> {
+    stationary(a, b, tolerance, max_iterations, |x| {
+        let size = x.size();
+        let mut next = Vector::from_elem(K::zero(), size);
+
+        for row in 0..size {
+            let mut value = b[row];
+            for col in 0..size {
+                if col != row {
+                    value = value - a[(col, row)] * x[col];
+                }
+            }
+            next[row] = value / a[(row, row)];
+        }
+
+        *x = next;
+    })
+}
+
+/// Gauss–Seidel iteration, starting from x = 0. Equivalent to SOR with ω = 1.
+pub fn gauss_seidel<K: Field>(a: &Matrix<K>, b: &Vector<K>, tolerance: K, max_iterations: usize) -> IterativeSolution<K> {
+    sor(a, b, K::one(), tolerance, max_iterations)
+}
+
+/// Successive over-relaxation with factor `omega` in (0, 2), starting from x = 0.
+pub fn sor<K: Field>(a: &Matrix<K>, b: &Vector<K>, omega: K, tolerance: K, max_iterations: usize) -> IterativeSolution<K> {
+    stationary(a, b, tolerance, max_iterations, |x| {
+        let size = x.size();
+
+        for row in 0..size {
+            let mut value = b[row];
+            for col in 0..size {
+                if col != row {
+                    value = value - a[(col, row)] * x[col];
+                }
+            }
+            x[row] = (K::one() - omega) * x[row] + omega * value / a[(row, row)];
+        }
+    })
+}
+
+fn stationary<K: Field, F: FnMut(&mut Vector<K>)>(a: &Matrix<K>, b: &Vector<K>, tolerance: K, max_iterations: usize, mut sweep: F) -> IterativeSolution<K> {
+    assert!(a.is_square(), "Stationary solvers require a square matrix");
+    assert_eq!(a.shape().0, b.size(), "Vector size must equal matrix width");
+    assert!((0..b.size()).all(|i| a[(i, i)] != K::zero()), "Stationary solvers require a non-zero diagonal");
+
+    let threshold = tolerance * b.norm();
+    let mut x = Vector::from_elem(K::zero(), b.size());
+    let mut history = vec![b.norm()];
+    let mut converged = history[0] <= threshold;
+    let mut iterations = 0;
+
+    while !converged && iterations < max_iterations {
+        sweep(&mut x);
+        iterations += 1;
+        history.push((b.clone() - a * &x).norm());
+        converged = history[iterations] <= threshold;
+    }
+
+    IterativeSolution { x, iterations, converged, history }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagonally_dominant() -> (Matrix<f64>, Vector<f64>) {
+        let a = Matrix::from_rows([
+            [10., -1., 2., 0.],
+            [-1., 11., -1., 3.],
+            [2., -1., 10., -1.],
+            [0., 3., -1., 8.],
+        ]);
+        let b = Vector::from([6., 25., -11., 15.]);
+        (a, b)
+    }
+
+    fn assert_solution(solution: &IterativeSolution<f64>) {
+        let expected = Vector::from([1., 2., -1., 1.]);
+        assert!(solution.converged);
+        assert!((solution.x.clone() - expected).norm() < 1e-8);
+        assert_eq!(solution.history.len(), solution.iterations + 1);
+    }
+
+    #[test]
+    fn test_jacobi() {
+        let (a, b) = diagonally_dominant();
+        assert_solution(&jacobi(&a, &b, 1e-10, 100));
+    }
+
+    #[test]
+    fn test_gauss_seidel() {
+        let (a, b) = diagonally_dominant();
+        let solution = gauss_seidel(&a, &b, 1e-10, 100);
+        assert_solution(&solution);
+        assert!(solution.iterations < jacobi(&a, &b, 1e-10, 100).iterations);
+    }
+
+    #[test]
+    fn test_sor() {
+        let (a, b) = diagonally_dominant();
+        assert_solution(&sor(&a, &b, 1.1, 1e-10, 100));
+
+        let solution = sor(&a, &b, 1.1, 1e-10, 2);
+        assert!(!solution.converged);
+        assert_eq!(solution.iterations, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_stationary_zero_diagonal_panic() {
+        let a = Matrix::from_rows([
+            [0., 1.],
+            [1., 0.],
+        ]);
+        let _ = jacobi(&a, &Vector::from([1., 1.]), 1e-10, 10);
+    }
+}
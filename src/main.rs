@@ -1,7 +1,11 @@
-use crate::core::matrix::Matrix;
-use crate::core::vector::Vector;
+use crate::core::{Matrix, Vector};
 
 mod core;
+mod io;
+mod iterative;
+mod operator;
+mod sparse;
+mod structured;
 mod traits;
 
 fn main() {
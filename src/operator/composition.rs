@@ -0,0 +1,197 @@
+#![allow(dead_code)]
+
+use crate::core::Vector;
+use crate::operator::LinearOperator;
+use crate::traits::Field;
+
+/// A + B
+pub struct SumOperator<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A, B> SumOperator<A, B> {
+    pub fn new<K: Field>(left: A, right: B) -> Self
+    where
+        A: LinearOperator<K>,
+        B: LinearOperator<K>,
+    {
+        assert_eq!(left.shape(), right.shape(), "Operator sum dimensions mismatch.");
+        SumOperator { left, right }
+    }
+}
+
+impl<K: Field, A: LinearOperator<K>, B: LinearOperator<K>> LinearOperator<K> for SumOperator<A, B> {
+    fn shape(&self) -> (usize, usize) {
+        self.left.shape()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.left.apply(x) + self.right.apply(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        Some(self.left.apply_transpose(x)? + self.right.apply_transpose(x)?)
+    }
+}
+
+/// A·B, applying B first.
+pub struct ProductOperator<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A, B> ProductOperator<A, B> {
+    pub fn new<K: Field>(left: A, right: B) -> Self
+    where
+        A: LinearOperator<K>,
+        B: LinearOperator<K>,
+    {
+        assert_eq!(left.shape().0, right.shape().1, "Left operator width must equal right operator height");
+        ProductOperator { left, right }
+    }
+}
+
+impl<K: Field, A: LinearOperator<K>, B: LinearOperator<K>> LinearOperator<K> for ProductOperator<A, B> {
+    fn shape(&self) -> (usize, usize) {
+        (self.right.shape().0, self.left.shape().1)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.left.apply(&self.right.apply(x))
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        self.right.apply_transpose(&self.left.apply_transpose(x)?)
+    }
+}
+
+/// k·A
+pub struct ScaledOperator<A, K> {
+    inner: A,
+    factor: K,
+}
+
+impl<A, K: Field> ScaledOperator<A, K> {
+    pub fn new(inner: A, factor: K) -> Self {
+        ScaledOperator { inner, factor }
+    }
+}
+
+impl<K: Field, A: LinearOperator<K>> LinearOperator<K> for ScaledOperator<A, K> {
+    fn shape(&self) -> (usize, usize) {
+        self.inner.shape()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.inner.apply(x) * self.factor
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        Some(self.inner.apply_transpose(x)? * self.factor)
+    }
+}
+
+/// Aᵀ. Applying it panics if the inner operator has no transpose.
+pub struct TransposedOperator<A> {
+    inner: A,
+}
+
+impl<A> TransposedOperator<A> {
+    pub fn new(inner: A) -> Self {
+        TransposedOperator { inner }
+    }
+}
+
+impl<K: Field, A: LinearOperator<K>> LinearOperator<K> for TransposedOperator<A> {
+    fn shape(&self) -> (usize, usize) {
+        let (width, height) = self.inner.shape();
+        (height, width)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.inner.apply_transpose(x).expect("Transposed operator requires apply_transpose")
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        Some(self.inner.apply(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Matrix;
+    use crate::operator::FnOperator;
+
+    #[test]
+    fn test_sum_operator() {
+        let a = Matrix::from_rows([
+            [1., 2.],
+            [3., 4.],
+        ]);
+        let b = Matrix::from_rows([
+            [0., 1.],
+            [1., 0.],
+        ]);
+        let x = Vector::from([1., -1.]);
+        let sum = (&a).plus(&b);
+        assert_eq!(sum.apply(&x), Vector::from([-2., 0.]));
+        assert_eq!(sum.apply_transpose(&x), Some(Vector::from([-3., -1.])));
+    }
+
+    #[test]
+    fn test_product_operator() {
+        let a = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let b = Matrix::from_rows([
+            [1., 0.],
+            [0., 1.],
+            [1., 1.],
+        ]);
+        let product = (&a).compose(&b);
+        assert_eq!(product.shape(), (2, 2));
+        assert_eq!(product.apply(&Vector::from([1., 2.])), Vector::from([14., 32.]));
+        assert_eq!(product.apply_transpose(&Vector::from([1., 0.])), Some(Vector::from([4., 5.])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_product_operator_panic() {
+        let a: Matrix<f64> = Matrix::from_elem(1., 3, 2);
+        let _ = (&a).compose(&a);
+    }
+
+    #[test]
+    fn test_scaled_and_transposed_operator() {
+        let a = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let op = (&a).scaled(2.).transposed();
+        assert_eq!(op.shape(), (2, 3));
+        assert_eq!(op.apply(&Vector::from([1., 1.])), Vector::from([10., 14., 18.]));
+        assert_eq!(op.apply_transpose(&Vector::from([1., 0., 0.])), Some(Vector::from([2., 8.])));
+    }
+
+    #[test]
+    fn test_normal_equations_operator() {
+        let a = Matrix::from_rows([
+            [1., 2.],
+            [3., 4.],
+            [5., 6.],
+        ]);
+        let normal = (&a).transposed().compose(&a);
+        let expected = &(a.transpose() * a.clone()) * &Vector::from([1., -1.]);
+        assert_eq!(normal.apply(&Vector::from([1., -1.])), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transposed_operator_panic() {
+        let op = FnOperator::new(2, 2, |x: &Vector<f64>| x.clone()).transposed();
+        let _ = op.apply(&Vector::from([1., 2.]));
+    }
+}
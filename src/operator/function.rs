@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+
+use crate::core::Vector;
+use crate::operator::LinearOperator;
+use crate::traits::Field;
+
+type Apply<'a, K> = Box<dyn Fn(&Vector<K>) -> Vector<K> + 'a>;
+
+/// Matrix-free operator backed by closures.
+pub struct FnOperator<'a, K: Field> {
+    shape: (usize, usize),
+    apply: Apply<'a, K>,
+    apply_transpose: Option<Apply<'a, K>>,
+}
+
+impl<'a, K: Field> FnOperator<'a, K> {
+    pub fn new<F>(width: usize, height: usize, apply: F) -> Self
+    where
+        F: Fn(&Vector<K>) -> Vector<K> + 'a,
+    {
+        FnOperator {
+            shape: (width, height),
+            apply: Box::new(apply),
+            apply_transpose: None,
+        }
+    }
+
+    pub fn with_transpose<F>(mut self, apply_transpose: F) -> Self
+    where
+        F: Fn(&Vector<K>) -> Vector<K> + 'a,
+    {
+        self.apply_transpose = Some(Box::new(apply_transpose));
+        self
+    }
+}
+
+impl<K: Field> LinearOperator<K> for FnOperator<'_, K> {
+    fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        assert_eq!(x.size(), self.shape.0, "Vector size must equal operator width");
+        let result = (self.apply)(x);
+        assert_eq!(result.size(), self.shape.1, "Operator returned a vector of the wrong size");
+        result
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        assert_eq!(x.size(), self.shape.1, "Vector size must equal operator height");
+        let apply_transpose = self.apply_transpose.as_ref()?;
+        let result = apply_transpose(x);
+        assert_eq!(result.size(), self.shape.0, "Operator returned a vector of the wrong size");
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn difference(size: usize) -> FnOperator<'static, f64> {
+        FnOperator::new(size, size - 1, move |x: &Vector<f64>| {
+            let mut result = Vector::from_elem(0., size - 1);
+            for i in 0..size - 1 {
+                result[i] = x[i + 1] - x[i];
+            }
+            result
+        })
+    }
+
+    #[test]
+    fn test_fn_operator() {
+        let op = difference(4);
+        assert_eq!(op.shape(), (4, 3));
+        assert_eq!(op.apply(&Vector::from([1., 4., 9., 16.])), Vector::from([3., 5., 7.]));
+        assert_eq!(op.apply_transpose(&Vector::from([1., 1., 1.])), None);
+    }
+
+    #[test]
+    fn test_fn_operator_transpose() {
+        let op = difference(3).with_transpose(|y: &Vector<f64>| Vector::from([-y[0], y[0] - y[1], y[1]]));
+        assert_eq!(op.apply_transpose(&Vector::from([1., 2.])), Some(Vector::from([-1., -1., 2.])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fn_operator_size_panic() {
+        let op = difference(4);
+        let _ = op.apply(&Vector::from([1., 2.]));
+    }
+}
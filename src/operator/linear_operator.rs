@@ -0,0 +1,106 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::operator::{ProductOperator, ScaledOperator, SumOperator, TransposedOperator};
+use crate::traits::{Field, MulAdd};
+
+/// Anything that can be applied to a vector like a matrix, without necessarily storing one.
+/// `shape` follows `Matrix::shape`: (width, height), i.e. (input size, output size).
+pub trait LinearOperator<K: Field> {
+    fn shape(&self) -> (usize, usize);
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K>;
+
+    /// Applies the transpose, or returns `None` when the operator cannot provide it.
+    fn apply_transpose(&self, _x: &Vector<K>) -> Option<Vector<K>> {
+        None
+    }
+
+    fn is_square(&self) -> bool {
+        let (width, height) = self.shape();
+        width == height
+    }
+
+    fn plus<B: LinearOperator<K>>(self, other: B) -> SumOperator<Self, B> where Self: Sized {
+        SumOperator::new(self, other)
+    }
+
+    /// Operator applying `other` first, then `self`.
+    fn compose<B: LinearOperator<K>>(self, other: B) -> ProductOperator<Self, B> where Self: Sized {
+        ProductOperator::new(self, other)
+    }
+
+    fn scaled(self, factor: K) -> ScaledOperator<Self, K> where Self: Sized {
+        ScaledOperator::new(self, factor)
+    }
+
+    fn transposed(self) -> TransposedOperator<Self> where Self: Sized {
+        TransposedOperator::new(self)
+    }
+}
+
+impl<K: Field, T: LinearOperator<K> + ?Sized> LinearOperator<K> for &T {
+    fn shape(&self) -> (usize, usize) {
+        (**self).shape()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        (**self).apply(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        (**self).apply_transpose(x)
+    }
+}
+
+impl<K: Field> LinearOperator<K> for Matrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        Matrix::shape(self)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self * x
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        let (width, height) = Matrix::shape(self);
+        assert_eq!(x.size(), height, "Vector size must equal matrix height");
+        let mut result = Vector::from_elem(K::zero(), width);
+
+        for col in 0..width {
+            for i in 0..height {
+                result[col] = MulAdd::mul_add(self[(col, i)], x[i], result[col]);
+            }
+        }
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_operator() {
+        let a = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        assert_eq!(LinearOperator::shape(&a), (3, 2));
+        assert!(!LinearOperator::is_square(&a));
+        assert_eq!(a.apply(&Vector::from([1., 0., -1.])), Vector::from([-2., -2.]));
+        assert_eq!(a.apply_transpose(&Vector::from([1., -1.])), Some(Vector::from([-3., -3., -3.])));
+        assert_eq!(a.apply_transpose(&Vector::from([1., -1.])), Some(&a.transpose() * &Vector::from([1., -1.])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_operator_panic() {
+        let a = Matrix::from_rows([
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let _ = a.apply(&Vector::from([1., 2.]));
+    }
+}
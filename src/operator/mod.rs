@@ -0,0 +1,8 @@
+mod composition;
+mod function;
+mod linear_operator;
+
+pub use composition::*;
+#[allow(unused_imports)]
+pub use function::*;
+pub use linear_operator::*;
@@ -0,0 +1,164 @@
+#![allow(dead_code)]
+
+use crate::core::Vector;
+use crate::sparse::{CsrMatrix, FactorizationError, SymbolicFactorization};
+use crate::traits::{Field, Sqrt};
+
+/// Sparse PAPᵀ = LLᵀ for symmetric positive-definite A. Only the lower triangle of A is read.
+#[derive(Debug, Clone)]
+pub struct SparseCholesky<K: Field> {
+    symbolic: SymbolicFactorization,
+    diagonal: Vec<K>,
+    lower: Vec<K>,
+}
+
+impl<K: Field> SparseCholesky<K> {
+    pub fn factor(symbolic: &SymbolicFactorization, a: &CsrMatrix<K>) -> Result<Self, FactorizationError> {
+        let mut cholesky = SparseCholesky {
+            symbolic: symbolic.clone(),
+            diagonal: vec![K::zero(); symbolic.size()],
+            lower: vec![K::zero(); symbolic.nnz_lower()],
+        };
+        cholesky.refactor(a)?;
+        Ok(cholesky)
+    }
+
+    pub fn symbolic(&self) -> &SymbolicFactorization {
+        &self.symbolic
+    }
+
+    /// Recomputes the numeric factor for a matrix with the analysed pattern,
+    /// reusing the symbolic analysis and the factor storage.
+    pub fn refactor(&mut self, a: &CsrMatrix<K>) -> Result<(), FactorizationError> {
+        let symbolic = &self.symbolic;
+        if !symbolic.matches(a) {
+            return Err(FactorizationError::PatternMismatch);
+        }
+
+        self.diagonal.fill(K::zero());
+        self.lower.fill(K::zero());
+        for (x, y, value) in a.triplets() {
+            if y < x {
+                continue;
+            }
+            let (i, j) = (symbolic.new_index(y), symbolic.new_index(x));
+            let (i, j) = (i.max(j), i.min(j));
+            if i == j {
+                self.diagonal[i] = value;
+            } else {
+                self.lower[symbolic.position(i, j)] = value;
+            }
+        }
+
+        for k in 0..symbolic.size() {
+            if self.diagonal[k] <= K::zero() {
+                return Err(FactorizationError::NotPositiveDefinite(k));
+            }
+            let pivot = Sqrt::sqrt(self.diagonal[k]);
+            self.diagonal[k] = pivot;
+
+            for p in symbolic.lane(k) {
+                self.lower[p] = self.lower[p] / pivot;
+            }
+            for p in symbolic.lane(k) {
+                let (i, l_ik) = (symbolic.indices[p], self.lower[p]);
+                for q in symbolic.lane(k) {
+                    let (j, l_jk) = (symbolic.indices[q], self.lower[q]);
+                    if j > i {
+                        break;
+                    }
+                    if i == j {
+                        self.diagonal[i] = self.diagonal[i] - l_ik * l_jk;
+                    } else {
+                        let position = symbolic.position(i, j);
+                        self.lower[position] = self.lower[position] - l_ik * l_jk;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        let symbolic = &self.symbolic;
+        let mut y = symbolic.permute(b);
+
+        // L y = Pb, column by column
+        for j in 0..symbolic.size() {
+            y[j] = y[j] / self.diagonal[j];
+            for p in symbolic.lane(j) {
+                let i = symbolic.indices[p];
+                y[i] = y[i] - self.lower[p] * y[j];
+            }
+        }
+        // Lᵀ z = y, using column j of L as row j of Lᵀ
+        for j in (0..symbolic.size()).rev() {
+            let mut value = y[j];
+            for p in symbolic.lane(j) {
+                value = value - self.lower[p] * y[symbolic.indices[p]];
+            }
+            y[j] = value / self.diagonal[j];
+        }
+
+        symbolic.unpermute(&y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sparse::FillOrdering;
+
+    fn poisson(n: usize, scale: f64) -> CsrMatrix<f64> {
+        let index = |x: usize, y: usize| y * n + x;
+        let mut triplets = Vec::new();
+        for y in 0..n {
+            for x in 0..n {
+                let i = index(x, y);
+                triplets.push((i, i, 4. * scale));
+                if x > 0 {
+                    triplets.push((index(x - 1, y), i, -scale));
+                    triplets.push((i, index(x - 1, y), -scale));
+                }
+                if y > 0 {
+                    triplets.push((index(x, y - 1), i, -scale));
+                    triplets.push((i, index(x, y - 1), -scale));
+                }
+            }
+        }
+        CsrMatrix::from_triplets(n * n, n * n, triplets)
+    }
+
+    #[test]
+    fn test_sparse_cholesky() {
+        let a = poisson(5, 1.);
+        let b = Vector::from_elem(1., 25);
+        for ordering in [FillOrdering::Natural, FillOrdering::ReverseCuthillMcKee, FillOrdering::MinimumDegree] {
+            let symbolic = SymbolicFactorization::analyze(&a, ordering);
+            let cholesky = SparseCholesky::factor(&symbolic, &a).unwrap();
+            assert!((b.clone() - &a * &cholesky.solve(&b)).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sparse_cholesky_refactor() {
+        let a = poisson(6, 1.);
+        let symbolic = SymbolicFactorization::analyze(&a, FillOrdering::ReverseCuthillMcKee);
+        let mut cholesky = SparseCholesky::factor(&symbolic, &a).unwrap();
+        let b = Vector::from_elem(1., 36);
+
+        for scale in [3., 0.25] {
+            let a = poisson(6, scale);
+            cholesky.refactor(&a).unwrap();
+            assert!((b.clone() - &a * &cholesky.solve(&b)).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sparse_cholesky_not_positive_definite() {
+        let a = CsrMatrix::from_triplets(2, 2, [(0, 0, 1.), (1, 0, 2.), (0, 1, 2.), (1, 1, 1.)]);
+        let symbolic = SymbolicFactorization::analyze(&a, FillOrdering::Natural);
+        assert_eq!(SparseCholesky::factor(&symbolic, &a).unwrap_err(), FactorizationError::NotPositiveDefinite(1));
+    }
+}
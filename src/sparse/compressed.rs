@@ -0,0 +1,177 @@
+#![allow(dead_code)]
+
+use crate::core::Vector;
+use crate::traits::{Field, MulAdd};
+use std::fmt;
+
+/// Storage shared by CSR and CSC: `major` is the compressed axis (rows for CSR,
+/// columns for CSC) and `indices` hold positions along the `minor` axis.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Compressed<K: Field> {
+    pub(super) major: usize,
+    pub(super) minor: usize,
+    pub(super) offsets: Vec<usize>,
+    pub(super) indices: Vec<usize>,
+    pub(super) values: Vec<K>,
+}
+
+impl<K: Field> Compressed<K> {
+    /// Builds from (major, minor, value) triplets, sorting each lane and summing duplicates.
+    pub(super) fn from_triplets<I>(major: usize, minor: usize, triplets: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, K)>,
+    {
+        let mut triplets: Vec<(usize, usize, K)> = triplets.into_iter().collect();
+        triplets.sort_by_key(|&(i, j, _)| (i, j));
+
+        let mut offsets = vec![0; major + 1];
+        let mut indices = Vec::with_capacity(triplets.len());
+        let mut values: Vec<K> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+
+        for (i, j, value) in triplets {
+            assert!(i < major && j < minor, "Sparse entry out of bounds");
+            if last == Some((i, j)) {
+                let end = values.len() - 1;
+                values[end] = values[end] + value;
+                continue;
+            }
+            offsets[i + 1] += 1;
+            indices.push(j);
+            values.push(value);
+            last = Some((i, j));
+        }
+        for i in 0..major {
+            offsets[i + 1] += offsets[i];
+        }
+
+        Compressed { major, minor, offsets, indices, values }
+    }
+
+    pub(super) fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub(super) fn lane(&self, i: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        let range = self.offsets[i]..self.offsets[i + 1];
+        self.indices[range.clone()].iter().copied().zip(self.values[range].iter().copied())
+    }
+
+    pub(super) fn triplets(&self) -> impl Iterator<Item = (usize, usize, K)> + '_ {
+        (0..self.major).flat_map(move |i| self.lane(i).map(move |(j, value)| (i, j, value)))
+    }
+
+    pub(super) fn position(&self, i: usize, j: usize) -> Option<usize> {
+        assert!(i < self.major && j < self.minor, "Sparse index out of bounds");
+        let start = self.offsets[i];
+        self.indices[start..self.offsets[i + 1]].binary_search(&j).ok().map(|p| start + p)
+    }
+
+    pub(super) fn get(&self, i: usize, j: usize) -> K {
+        self.position(i, j).map_or(K::zero(), |p| self.values[p])
+    }
+
+    /// Same entries, compressed along the other axis.
+    pub(super) fn transpose(&self) -> Self {
+        let mut offsets = vec![0; self.minor + 1];
+        for &j in &self.indices {
+            offsets[j + 1] += 1;
+        }
+        for j in 0..self.minor {
+            offsets[j + 1] += offsets[j];
+        }
+
+        let mut next = offsets.clone();
+        let mut indices = vec![0; self.nnz()];
+        let mut values = vec![K::zero(); self.nnz()];
+        for i in 0..self.major {
+            for (j, value) in self.lane(i) {
+                indices[next[j]] = i;
+                values[next[j]] = value;
+                next[j] += 1;
+            }
+        }
+
+        Compressed { major: self.minor, minor: self.major, offsets, indices, values }
+    }
+
+    /// Gustavson's product: lane i of the result is Σ self(i, k) · other lane k.
+    pub(super) fn product(&self, other: &Compressed<K>) -> Self {
+        assert_eq!(self.minor, other.major, "Sparse product dimensions mismatch.");
+        let mut offsets = vec![0; self.major + 1];
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        let mut accumulator = vec![K::zero(); other.minor];
+        let mut marker = vec![usize::MAX; other.minor];
+        let mut touched = Vec::new();
+
+        for i in 0..self.major {
+            for (k, a) in self.lane(i) {
+                for (j, b) in other.lane(k) {
+                    if marker[j] != i {
+                        marker[j] = i;
+                        accumulator[j] = K::zero();
+                        touched.push(j);
+                    }
+                    accumulator[j] = MulAdd::mul_add(a, b, accumulator[j]);
+                }
+            }
+
+            touched.sort_unstable();
+            for &j in &touched {
+                indices.push(j);
+                values.push(accumulator[j]);
+            }
+            touched.clear();
+            offsets[i + 1] = indices.len();
+        }
+
+        Compressed { major: self.major, minor: other.minor, offsets, indices, values }
+    }
+
+    /// y[i] = Σ self(i, j) · x[j]
+    pub(super) fn gather(&self, x: &Vector<K>) -> Vector<K> {
+        assert_eq!(x.size(), self.minor, "Vector size must equal sparse matrix width");
+        let mut result = Vector::from_elem(K::zero(), self.major);
+
+        for i in 0..self.major {
+            result[i] = self.lane(i).fold(K::zero(), |acc, (j, value)| MulAdd::mul_add(value, x[j], acc));
+        }
+
+        result
+    }
+
+    /// y[j] = Σ self(i, j) · x[i]
+    pub(super) fn scatter(&self, x: &Vector<K>) -> Vector<K> {
+        assert_eq!(x.size(), self.major, "Vector size must equal sparse matrix height");
+        let mut result = Vector::from_elem(K::zero(), self.minor);
+
+        for i in 0..self.major {
+            for (j, value) in self.lane(i) {
+                result[j] = MulAdd::mul_add(value, x[i], result[j]);
+            }
+        }
+
+        result
+    }
+}
+
+/// Writes a spy-style pattern with `●` for stored entries and `·` elsewhere.
+pub(super) fn write_pattern<F>(f: &mut fmt::Formatter<'_>, (width, height): (usize, usize), stored: F) -> fmt::Result
+where
+    F: Fn(usize, usize) -> bool,
+{
+    for y in 0..height {
+        for x in 0..width {
+            write!(f, "{}", if stored(x, y) { '●' } else { '·' })?;
+            if x < width - 1 {
+                write!(f, " ")?;
+            }
+        }
+        if y < height - 1 {
+            writeln!(f)?;
+        }
+    }
+
+    Ok(())
+}
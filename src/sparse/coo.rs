@@ -0,0 +1,139 @@
+#![allow(dead_code)]
+
+use crate::core::Matrix;
+use crate::sparse::compressed::write_pattern;
+use crate::sparse::{CscMatrix, CsrMatrix};
+use crate::traits::Field;
+use std::fmt;
+
+/// Coordinate-format matrix for assembly. Entries are (x, y, value) with
+/// (x, y) = (column, row) like `Matrix`; duplicates are summed on conversion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<K: Field> {
+    shape: (usize, usize),
+    entries: Vec<(usize, usize, K)>,
+}
+
+impl<K: Field> CooMatrix<K> {
+    pub fn new(width: usize, height: usize) -> Self {
+        CooMatrix {
+            shape: (width, height),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn from_triplets<I>(width: usize, height: usize, triplets: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, K)>,
+    {
+        let mut result = CooMatrix::new(width, height);
+        for (x, y, value) in triplets {
+            result.push(x, y, value);
+        }
+        result
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// Number of stored entries, counting duplicates.
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn push(&mut self, x: usize, y: usize, value: K) {
+        assert!(x < self.shape.0);
+        assert!(y < self.shape.1);
+        self.entries.push((x, y, value));
+    }
+
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, K)> + '_ {
+        self.entries.iter().copied()
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<K> {
+        CsrMatrix::from_triplets(self.shape.0, self.shape.1, self.triplets())
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<K> {
+        CscMatrix::from_triplets(self.shape.0, self.shape.1, self.triplets())
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut result = Matrix::from_elem(K::zero(), self.shape.0, self.shape.1);
+
+        for &(x, y, value) in &self.entries {
+            result[(x, y)] = result[(x, y)] + value;
+        }
+
+        result
+    }
+}
+
+impl<K: Field> From<&Matrix<K>> for CooMatrix<K> {
+    fn from(value: &Matrix<K>) -> Self {
+        let (width, height) = value.shape();
+        let mut result = CooMatrix::new(width, height);
+
+        for x in 0..width {
+            for y in 0..height {
+                if value[(x, y)] != K::zero() {
+                    result.push(x, y, value[(x, y)]);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<K: Field> fmt::Display for CooMatrix<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut stored = vec![false; self.shape.0 * self.shape.1];
+        for &(x, y, _) in &self.entries {
+            stored[x * self.shape.1 + y] = true;
+        }
+
+        write_pattern(f, self.shape, |x, y| stored[x * self.shape.1 + y])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coo_assembly() {
+        let mut coo = CooMatrix::new(3, 2);
+        coo.push(0, 0, 1.);
+        coo.push(2, 1, 2.);
+        coo.push(2, 1, 3.);
+        assert_eq!(coo.nnz(), 3);
+        assert_eq!(coo.to_dense(), Matrix::from_rows([
+            [1., 0., 0.],
+            [0., 0., 5.],
+        ]));
+        assert_eq!(coo.to_csr().nnz(), 2);
+        assert_eq!(coo.to_csc().to_dense(), coo.to_dense());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_coo_push_panic() {
+        let mut coo = CooMatrix::new(3, 2);
+        coo.push(0, 2, 1.);
+    }
+
+    #[test]
+    fn test_coo_from_dense() {
+        let a = Matrix::from_rows([
+            [1., 0., 2.],
+            [0., 0., 3.],
+        ]);
+        let coo = CooMatrix::from(&a);
+        assert_eq!(coo.nnz(), 3);
+        assert_eq!(coo.to_dense(), a);
+        assert_eq!(coo.to_string(), "● · ●\n· · ●");
+    }
+}
@@ -0,0 +1,210 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::operator::LinearOperator;
+use crate::sparse::compressed::{write_pattern, Compressed};
+use crate::sparse::{CooMatrix, CsrMatrix};
+use crate::traits::Field;
+use std::{fmt, ops};
+
+/// Compressed sparse column matrix. Like `Matrix`, indices are (x, y) = (column, row).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<K: Field> {
+    storage: Compressed<K>,
+}
+
+impl<K: Field> CscMatrix<K> {
+    pub(super) fn from_storage(storage: Compressed<K>) -> Self {
+        CscMatrix { storage }
+    }
+
+    /// Builds from (x, y, value) triplets; duplicate positions are summed.
+    pub fn from_triplets<I>(width: usize, height: usize, triplets: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, K)>,
+    {
+        CscMatrix { storage: Compressed::from_triplets(width, height, triplets) }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.storage.major, self.storage.minor)
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+
+    pub fn column_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+
+    pub fn row_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    pub fn values(&self) -> &[K] {
+        &self.storage.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [K] {
+        &mut self.storage.values
+    }
+
+    /// Stored entries of column `x` as (y, value) pairs, in increasing row order.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        self.storage.lane(x)
+    }
+
+    /// Stored entries as (x, y, value) triplets, in column-major order.
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, K)> + '_ {
+        self.storage.triplets()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> K {
+        self.storage.get(x, y)
+    }
+
+    pub fn transpose(&self) -> CscMatrix<K> {
+        CscMatrix { storage: self.storage.transpose() }
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<K> {
+        CsrMatrix::from_storage(self.storage.transpose())
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<K> {
+        let (width, height) = self.shape();
+        CooMatrix::from_triplets(width, height, self.triplets())
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let (width, height) = self.shape();
+        let mut result = Matrix::from_elem(K::zero(), width, height);
+
+        for (x, y, value) in self.triplets() {
+            result[(x, y)] = value;
+        }
+
+        result
+    }
+}
+
+impl<K: Field> From<&Matrix<K>> for CscMatrix<K> {
+    fn from(value: &Matrix<K>) -> Self {
+        CooMatrix::from(value).to_csc()
+    }
+}
+
+impl<K: Field> fmt::Display for CscMatrix<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_pattern(f, self.shape(), |x, y| self.storage.position(x, y).is_some())
+    }
+}
+
+impl<K: Field> ops::Mul<&Vector<K>> for &CscMatrix<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        self.storage.scatter(rhs)
+    }
+}
+
+impl<K: Field> ops::Mul<&CscMatrix<K>> for &CscMatrix<K> {
+    type Output = CscMatrix<K>;
+
+    fn mul(self, rhs: &CscMatrix<K>) -> Self::Output {
+        assert_eq!(self.shape().0, rhs.shape().1, "Left matrix width must equal right matrix height");
+        // Column j of AB is Σ B(k, j) · column k of A
+        CscMatrix { storage: rhs.storage.product(&self.storage) }
+    }
+}
+
+impl<K: Field> LinearOperator<K> for CscMatrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        CscMatrix::shape(self)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.storage.scatter(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        Some(self.storage.gather(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csc_layout() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let csc = CscMatrix::from(&dense);
+        assert_eq!(csc.shape(), (4, 3));
+        assert_eq!(csc.column_offsets(), [0, 1, 2, 3, 4]);
+        assert_eq!(csc.row_indices(), [0, 2, 0, 2]);
+        assert_eq!(csc.values(), [1., 3., 2., 4.]);
+        assert_eq!(csc.get(1, 2), 3.);
+        assert_eq!(csc.column(2).collect::<Vec<_>>(), [(0, 2.)]);
+        assert_eq!(csc.to_dense(), dense);
+    }
+
+    #[test]
+    fn test_csc_transpose() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let csc = CscMatrix::from(&dense);
+        assert_eq!(csc.transpose().to_dense(), dense.transpose());
+        assert_eq!(csc.to_csr().to_csc(), csc);
+        assert_eq!(csc.to_coo().to_dense(), dense);
+    }
+
+    #[test]
+    fn test_csc_mult_vec() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let csc = CscMatrix::from(&dense);
+        let u = Vector::from([1., 2., 3., 4.]);
+        assert_eq!(&csc * &u, &dense * &u);
+        assert_eq!(csc.apply_transpose(&Vector::from([1., 0., 1.])), Some(Vector::from([1., 3., 2., 4.])));
+    }
+
+    #[test]
+    fn test_csc_mult_csc() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let a = CscMatrix::from(&dense);
+        let b = a.transpose();
+        assert_eq!((&a * &b).to_dense(), Matrix::from_rows([
+            [5., 0., 0.],
+            [0., 0., 0.],
+            [0., 0., 25.],
+        ]));
+        assert_eq!((&b * &a).to_dense(), dense.transpose() * dense);
+    }
+
+    #[test]
+    fn test_csc_display() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let csc = CscMatrix::from(&dense);
+        assert_eq!(csc.to_string(), CsrMatrix::from(&dense).to_string());
+    }
+}
@@ -0,0 +1,234 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::operator::LinearOperator;
+use crate::sparse::compressed::{write_pattern, Compressed};
+use crate::sparse::{CooMatrix, CscMatrix};
+use crate::traits::Field;
+use std::{fmt, ops};
+
+/// Compressed sparse row matrix. Like `Matrix`, indices are (x, y) = (column, row).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<K: Field> {
+    storage: Compressed<K>,
+}
+
+impl<K: Field> CsrMatrix<K> {
+    pub(super) fn from_storage(storage: Compressed<K>) -> Self {
+        CsrMatrix { storage }
+    }
+
+    /// Builds from (x, y, value) triplets; duplicate positions are summed.
+    pub fn from_triplets<I>(width: usize, height: usize, triplets: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, K)>,
+    {
+        let storage = Compressed::from_triplets(height, width, triplets.into_iter().map(|(x, y, value)| (y, x, value)));
+        CsrMatrix { storage }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.storage.minor, self.storage.major)
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+
+    pub fn column_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    pub fn values(&self) -> &[K] {
+        &self.storage.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [K] {
+        &mut self.storage.values
+    }
+
+    /// Stored entries of row `y` as (x, value) pairs, in increasing column order.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        self.storage.lane(y)
+    }
+
+    /// Stored entries as (x, y, value) triplets, in row-major order.
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, K)> + '_ {
+        self.storage.triplets().map(|(y, x, value)| (x, y, value))
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> K {
+        self.storage.get(y, x)
+    }
+
+    pub fn transpose(&self) -> CsrMatrix<K> {
+        // Compressing A's rows along the other axis yields A's columns, i.e. the rows of Aᵀ
+        CsrMatrix { storage: self.storage.transpose() }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<K> {
+        CscMatrix::from_storage(self.storage.transpose())
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<K> {
+        let (width, height) = self.shape();
+        CooMatrix::from_triplets(width, height, self.triplets())
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let (width, height) = self.shape();
+        let mut result = Matrix::from_elem(K::zero(), width, height);
+
+        for (x, y, value) in self.triplets() {
+            result[(x, y)] = value;
+        }
+
+        result
+    }
+}
+
+impl<K: Field> From<&Matrix<K>> for CsrMatrix<K> {
+    fn from(value: &Matrix<K>) -> Self {
+        CooMatrix::from(value).to_csr()
+    }
+}
+
+impl<K: Field> fmt::Display for CsrMatrix<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_pattern(f, self.shape(), |x, y| self.storage.position(y, x).is_some())
+    }
+}
+
+impl<K: Field> ops::Mul<&Vector<K>> for &CsrMatrix<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        self.storage.gather(rhs)
+    }
+}
+
+impl<K: Field> ops::Mul<&CsrMatrix<K>> for &CsrMatrix<K> {
+    type Output = CsrMatrix<K>;
+
+    fn mul(self, rhs: &CsrMatrix<K>) -> Self::Output {
+        assert_eq!(self.shape().0, rhs.shape().1, "Left matrix width must equal right matrix height");
+        CsrMatrix { storage: self.storage.product(&rhs.storage) }
+    }
+}
+
+impl<K: Field> LinearOperator<K> for CsrMatrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        CsrMatrix::shape(self)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.storage.gather(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        Some(self.storage.scatter(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csr_layout() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let csr = CsrMatrix::from(&dense);
+        assert_eq!(csr.shape(), (4, 3));
+        assert_eq!(csr.nnz(), 4);
+        assert_eq!(csr.row_offsets(), [0, 2, 2, 4]);
+        assert_eq!(csr.column_indices(), [0, 2, 1, 3]);
+        assert_eq!(csr.values(), [1., 2., 3., 4.]);
+        assert_eq!(csr.get(3, 2), 4.);
+        assert_eq!(csr.get(1, 0), 0.);
+        assert_eq!(csr.row(2).collect::<Vec<_>>(), [(1, 3.), (3, 4.)]);
+        assert_eq!(csr.to_dense(), dense);
+    }
+
+    #[test]
+    fn test_csr_duplicates() {
+        let csr = CsrMatrix::from_triplets(2, 2, [(1, 0, 1.), (0, 1, 2.), (1, 0, 3.)]);
+        assert_eq!(csr.nnz(), 2);
+        assert_eq!(csr.get(1, 0), 4.);
+    }
+
+    #[test]
+    fn test_csr_transpose() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let csr = CsrMatrix::from(&dense);
+        assert_eq!(csr.transpose().to_dense(), dense.transpose());
+        assert_eq!(csr.to_csc().to_dense(), dense);
+        assert_eq!(csr.to_coo().to_csr(), csr);
+    }
+
+    #[test]
+    fn test_csr_mult_vec() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let csr = CsrMatrix::from(&dense);
+        let u = Vector::from([1., 2., 3., 4.]);
+        assert_eq!(&csr * &u, &dense * &u);
+        assert_eq!(csr.apply_transpose(&Vector::from([1., 1., 1.])), Some(Vector::from([1., 3., 2., 4.])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_csr_mult_vec_panic() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let csr = CsrMatrix::from(&dense);
+        let _ = &csr * &Vector::from([1., 2., 3.]);
+    }
+
+    #[test]
+    fn test_csr_mult_csr() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let a = CsrMatrix::from(&dense);
+        let b = a.transpose();
+        let product = &a * &b;
+        assert_eq!(product.to_dense(), Matrix::from_rows([
+            [5., 0., 0.],
+            [0., 0., 0.],
+            [0., 0., 25.],
+        ]));
+        assert_eq!(product.nnz(), 2);
+        assert_eq!((&b * &a).to_dense(), dense.transpose() * dense);
+    }
+
+    #[test]
+    fn test_csr_display() {
+        let dense = Matrix::from_rows([
+            [1., 0., 2., 0.],
+            [0., 0., 0., 0.],
+            [0., 3., 0., 4.],
+        ]);
+        let csr = CsrMatrix::from(&dense);
+        assert_eq!(csr.to_string(), "● · ● ·\n· · · ·\n· ● · ●");
+    }
+}
@@ -0,0 +1,23 @@
+use std::{error, fmt};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FactorizationError {
+    /// The matrix does not have the sparsity pattern the symbolic analysis was built for.
+    PatternMismatch,
+    /// A zero pivot appeared at the given (permuted) step.
+    ZeroPivot(usize),
+    /// A non-positive pivot appeared at the given (permuted) step of a Cholesky factorization.
+    NotPositiveDefinite(usize),
+}
+
+impl fmt::Display for FactorizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FactorizationError::PatternMismatch => write!(f, "matrix pattern differs from the symbolic analysis"),
+            FactorizationError::ZeroPivot(step) => write!(f, "zero pivot at step {step}"),
+            FactorizationError::NotPositiveDefinite(step) => write!(f, "matrix is not positive definite (step {step})"),
+        }
+    }
+}
+
+impl error::Error for FactorizationError {}
@@ -0,0 +1,20 @@
+mod cholesky;
+mod compressed;
+mod coo;
+mod csc;
+mod csr;
+mod error;
+mod lu;
+mod ordering;
+mod symbolic;
+
+#[allow(unused_imports)]
+pub use cholesky::*;
+pub use coo::*;
+pub use csc::*;
+pub use csr::*;
+pub use error::*;
+#[allow(unused_imports)]
+pub use lu::*;
+pub use ordering::*;
+pub use symbolic::*;
@@ -0,0 +1,192 @@
+#![allow(dead_code)]
+
+use crate::sparse::CsrMatrix;
+use crate::traits::Field;
+use std::collections::{BTreeSet, VecDeque};
+
+/// Fill-reducing orderings for sparse factorization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillOrdering {
+    Natural,
+    ReverseCuthillMcKee,
+    MinimumDegree,
+}
+
+impl FillOrdering {
+    /// Returns `order` with `order[new] = old`, computed on the pattern of A + Aᵀ.
+    pub fn compute<K: Field>(self, a: &CsrMatrix<K>) -> Vec<usize> {
+        match self {
+            FillOrdering::Natural => (0..a.shape().0).collect(),
+            FillOrdering::ReverseCuthillMcKee => reverse_cuthill_mckee(&symmetric_graph(a)),
+            FillOrdering::MinimumDegree => minimum_degree(symmetric_graph(a)),
+        }
+    }
+}
+
+/// Adjacency of A + Aᵀ without self loops.
+pub(super) fn symmetric_graph<K: Field>(a: &CsrMatrix<K>) -> Vec<BTreeSet<usize>> {
+    assert_eq!(a.shape().0, a.shape().1, "Orderings are only defined for square matrices");
+    let mut graph = vec![BTreeSet::new(); a.shape().0];
+
+    for (x, y, _) in a.triplets() {
+        if x != y {
+            graph[x].insert(y);
+            graph[y].insert(x);
+        }
+    }
+
+    graph
+}
+
+fn reverse_cuthill_mckee(graph: &[BTreeSet<usize>]) -> Vec<usize> {
+    let size = graph.len();
+    let mut order = Vec::with_capacity(size);
+    let mut visited = vec![false; size];
+
+    while order.len() < size {
+        // Start each component from a pseudo-peripheral node
+        let seed = (0..size).filter(|&v| !visited[v]).min_by_key(|&v| graph[v].len()).unwrap();
+        let start = pseudo_peripheral(graph, seed);
+
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            let mut neighbours: Vec<usize> = graph[v].iter().copied().filter(|&w| !visited[w]).collect();
+            neighbours.sort_by_key(|&w| graph[w].len());
+            for w in neighbours {
+                visited[w] = true;
+                queue.push_back(w);
+            }
+        }
+    }
+
+    order.reverse();
+    order
+}
+
+fn pseudo_peripheral(graph: &[BTreeSet<usize>], start: usize) -> usize {
+    let mut node = start;
+    let mut eccentricity = 0;
+
+    loop {
+        let levels = bfs_levels(graph, node);
+        let depth = levels.iter().flatten().copied().max().unwrap_or(0);
+        if depth <= eccentricity && node != start {
+            return node;
+        }
+        eccentricity = depth;
+
+        let farthest = (0..graph.len())
+            .filter(|&v| levels[v] == Some(depth))
+            .min_by_key(|&v| graph[v].len())
+            .unwrap();
+        if farthest == node {
+            return node;
+        }
+        node = farthest;
+    }
+}
+
+fn bfs_levels(graph: &[BTreeSet<usize>], start: usize) -> Vec<Option<usize>> {
+    let mut levels = vec![None; graph.len()];
+    levels[start] = Some(0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(v) = queue.pop_front() {
+        let level = levels[v].unwrap();
+        for &w in &graph[v] {
+            if levels[w].is_none() {
+                levels[w] = Some(level + 1);
+                queue.push_back(w);
+            }
+        }
+    }
+
+    levels
+}
+
+/// Greedy minimum degree on the explicit elimination graph. Ties go to the lowest index.
+fn minimum_degree(mut graph: Vec<BTreeSet<usize>>) -> Vec<usize> {
+    let size = graph.len();
+    let mut order = Vec::with_capacity(size);
+    let mut eliminated = vec![false; size];
+
+    for _ in 0..size {
+        let v = (0..size).filter(|&v| !eliminated[v]).min_by_key(|&v| graph[v].len()).unwrap();
+        eliminated[v] = true;
+        order.push(v);
+
+        // Eliminating v turns its neighbourhood into a clique
+        let neighbours: Vec<usize> = graph[v].iter().copied().collect();
+        for &w in &neighbours {
+            graph[w].remove(&v);
+            for &u in &neighbours {
+                if u != w {
+                    graph[w].insert(u);
+                }
+            }
+        }
+        graph[v].clear();
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_permutation(order: &[usize]) -> bool {
+        let mut sorted = order.to_vec();
+        sorted.sort_unstable();
+        sorted.into_iter().eq(0..order.len())
+    }
+
+    fn path(size: usize) -> CsrMatrix<f64> {
+        let mut triplets = Vec::new();
+        for i in 0..size {
+            triplets.push((i, i, 2.));
+            if i > 0 {
+                triplets.push((i - 1, i, -1.));
+                triplets.push((i, i - 1, -1.));
+            }
+        }
+        CsrMatrix::from_triplets(size, size, triplets)
+    }
+
+    #[test]
+    fn test_natural_ordering() {
+        assert_eq!(FillOrdering::Natural.compute(&path(4)), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_reverse_cuthill_mckee() {
+        // Path graph shuffled: 0 - 3 - 1 - 4 - 2
+        let a = CsrMatrix::from_triplets(5, 5, [(0, 3, 1.), (3, 1, 1.), (1, 4, 1.), (4, 2, 1.)]);
+        let order = FillOrdering::ReverseCuthillMcKee.compute(&a);
+        assert!(is_permutation(&order));
+        for window in order.windows(2) {
+            assert!(a.get(window[0], window[1]) != 0. || a.get(window[1], window[0]) != 0.);
+        }
+    }
+
+    #[test]
+    fn test_reverse_cuthill_mckee_disconnected() {
+        let a = CsrMatrix::from_triplets(4, 4, [(0, 2, 1.), (1, 3, 1.)]);
+        assert!(is_permutation(&FillOrdering::ReverseCuthillMcKee.compute(&a)));
+    }
+
+    #[test]
+    fn test_minimum_degree_arrow() {
+        // Node 0 touches everything; eliminating it first would fill the whole matrix
+        let mut triplets = vec![(0, 0, 1.)];
+        for i in 1..6 {
+            triplets.extend([(0, i, 1.), (i, 0, 1.), (i, i, 1.)]);
+        }
+        let a = CsrMatrix::from_triplets(6, 6, triplets);
+        let order = FillOrdering::MinimumDegree.compute(&a);
+        assert!(is_permutation(&order));
+        assert!(!order[..4].contains(&0));
+    }
+}
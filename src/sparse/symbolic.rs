@@ -0,0 +1,162 @@
+#![allow(dead_code)]
+
+use crate::core::Vector;
+use crate::sparse::{CsrMatrix, FillOrdering};
+use crate::traits::Field;
+use std::ops::Range;
+
+/// Ordering and fill pattern of a sparse factorization, computed once from the
+/// pattern of A + Aᵀ and reusable for every matrix sharing A's pattern.
+///
+/// The strictly lower pattern of L is stored by column; by symmetry of the
+/// pattern it is also the strictly upper pattern of U stored by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolicFactorization {
+    order: Vec<usize>,
+    inverse: Vec<usize>,
+    pub(super) offsets: Vec<usize>,
+    pub(super) indices: Vec<usize>,
+    row_offsets: Vec<usize>,
+    column_indices: Vec<usize>,
+}
+
+impl SymbolicFactorization {
+    pub fn analyze<K: Field>(a: &CsrMatrix<K>, ordering: FillOrdering) -> Self {
+        assert_eq!(a.shape().0, a.shape().1, "Sparse factorization is only defined for square matrices");
+        let size = a.shape().0;
+        let order = ordering.compute(a);
+        let mut inverse = vec![0; size];
+        for (new, &old) in order.iter().enumerate() {
+            inverse[old] = new;
+        }
+
+        // Lower pattern of the permuted A + Aᵀ, by column
+        let mut columns: Vec<Vec<usize>> = vec![Vec::new(); size];
+        for (x, y, _) in a.triplets() {
+            let (i, j) = (inverse[y], inverse[x]);
+            if i != j {
+                columns[i.min(j)].push(i.max(j));
+            }
+        }
+
+        // Column j of L is its own pattern merged with its elimination tree children
+        for j in 0..size {
+            columns[j].sort_unstable();
+            columns[j].dedup();
+            if let Some(&parent) = columns[j].first() {
+                let inherited: Vec<usize> = columns[j][1..].to_vec();
+                columns[parent].extend(inherited);
+            }
+        }
+
+        let mut offsets = vec![0; size + 1];
+        let mut indices = Vec::new();
+        for (j, column) in columns.into_iter().enumerate() {
+            indices.extend(column);
+            offsets[j + 1] = indices.len();
+        }
+
+        SymbolicFactorization {
+            order,
+            inverse,
+            offsets,
+            indices,
+            row_offsets: a.row_offsets().to_vec(),
+            column_indices: a.column_indices().to_vec(),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.order.len()
+    }
+
+    /// `order[new] = old`: row and column `new` of the factors are `old` in A.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Entries strictly below the diagonal of L, fill-in included.
+    pub fn nnz_lower(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub(super) fn new_index(&self, old: usize) -> usize {
+        self.inverse[old]
+    }
+
+    pub(super) fn matches<K: Field>(&self, a: &CsrMatrix<K>) -> bool {
+        a.row_offsets() == self.row_offsets && a.column_indices() == self.column_indices
+    }
+
+    pub(super) fn lane(&self, j: usize) -> Range<usize> {
+        self.offsets[j]..self.offsets[j + 1]
+    }
+
+    /// Position of row `i` in the pattern of column `j`, for i > j.
+    pub(super) fn position(&self, i: usize, j: usize) -> usize {
+        let range = self.lane(j);
+        let start = range.start;
+        start + self.indices[range].binary_search(&i).expect("fill entry outside the symbolic pattern")
+    }
+
+    /// Permuted (row, column, value) entries of A.
+    pub(super) fn permuted<'a, K: Field>(&'a self, a: &'a CsrMatrix<K>) -> impl Iterator<Item = (usize, usize, K)> + 'a {
+        a.triplets().map(|(x, y, value)| (self.new_index(y), self.new_index(x), value))
+    }
+
+    /// y[new] = b[old]
+    pub(super) fn permute<K: Field>(&self, b: &Vector<K>) -> Vector<K> {
+        assert_eq!(b.size(), self.size(), "Vector size must equal matrix width");
+        let mut result = Vector::from_elem(K::zero(), self.size());
+
+        for (new, &old) in self.order.iter().enumerate() {
+            result[new] = b[old];
+        }
+
+        result
+    }
+
+    /// x[old] = y[new]
+    pub(super) fn unpermute<K: Field>(&self, y: &Vector<K>) -> Vector<K> {
+        let mut result = Vector::from_elem(K::zero(), self.size());
+
+        for (new, &old) in self.order.iter().enumerate() {
+            result[old] = y[new];
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arrow(size: usize) -> CsrMatrix<f64> {
+        let mut triplets = vec![(0, 0, 1.)];
+        for i in 1..size {
+            triplets.extend([(0, i, 1.), (i, 0, 1.), (i, i, 1.)]);
+        }
+        CsrMatrix::from_triplets(size, size, triplets)
+    }
+
+    #[test]
+    fn test_symbolic_fill() {
+        let a = arrow(6);
+        let natural = SymbolicFactorization::analyze(&a, FillOrdering::Natural);
+        assert_eq!(natural.nnz_lower(), 15);
+        let minimum_degree = SymbolicFactorization::analyze(&a, FillOrdering::MinimumDegree);
+        assert_eq!(minimum_degree.nnz_lower(), 5);
+    }
+
+    #[test]
+    fn test_symbolic_permutation() {
+        let a = arrow(4);
+        let symbolic = SymbolicFactorization::analyze(&a, FillOrdering::MinimumDegree);
+        let b = Vector::from([1., 2., 3., 4.]);
+        assert_eq!(symbolic.unpermute(&symbolic.permute(&b)), b);
+        for (new, &old) in symbolic.order().iter().enumerate() {
+            assert_eq!(symbolic.new_index(old), new);
+        }
+    }
+}
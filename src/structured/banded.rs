@@ -0,0 +1,335 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::operator::LinearOperator;
+use crate::structured::Permutation;
+use crate::traits::{Abs, Field, MulAdd};
+use std::ops;
+
+/// A square matrix with `lower` subdiagonals and `upper` superdiagonals, in LAPACK-style
+/// band storage: each column holds `lower + upper + 1` slots, top superdiagonal first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Banded<K: Field> {
+    size: usize,
+    lower: usize,
+    upper: usize,
+    data: Vec<K>,
+}
+
+impl<K: Field> Banded<K> {
+    pub fn zeros(size: usize, lower: usize, upper: usize) -> Self {
+        Banded { size, lower, upper, data: vec![K::zero(); size * (lower + upper + 1)] }
+    }
+
+    /// Keeps the band of a square matrix; elements outside it are dropped.
+    pub fn from_matrix(matrix: &Matrix<K>, lower: usize, upper: usize) -> Self {
+        assert!(matrix.is_square(), "Banded matrices must be square");
+        let mut result = Banded::zeros(matrix.shape().0, lower, upper);
+
+        for x in 0..result.size {
+            for y in result.column_range(x) {
+                result.set(x, y, matrix[(x, y)]);
+            }
+        }
+
+        result
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// (lower, upper) bandwidths.
+    pub fn bandwidths(&self) -> (usize, usize) {
+        (self.lower, self.upper)
+    }
+
+    /// Rows of column `x` inside the band.
+    fn column_range(&self, x: usize) -> ops::Range<usize> {
+        x.saturating_sub(self.upper)..(x + self.lower + 1).min(self.size)
+    }
+
+    fn position(&self, x: usize, y: usize) -> Option<usize> {
+        assert!(x < self.size && y < self.size);
+        self.column_range(x)
+            .contains(&y)
+            .then(|| x * (self.lower + self.upper + 1) + self.upper + y - x)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> K {
+        self.position(x, y).map_or(K::zero(), |i| self.data[i])
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: K) {
+        let i = self.position(x, y).expect("Element outside the band");
+        self.data[i] = value;
+    }
+
+    /// LU factorization with partial pivoting, or `None` when the matrix is singular.
+    pub fn factor(&self) -> Option<BandedLu<K>> {
+        BandedLu::new(self)
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Option<Vector<K>> {
+        self.factor().map(|lu| lu.solve(b))
+    }
+
+    pub fn determinant(&self) -> K {
+        self.factor().map_or(K::zero(), |lu| lu.determinant())
+    }
+
+    pub fn transpose(&self) -> Banded<K> {
+        let mut result = Banded::zeros(self.size, self.upper, self.lower);
+
+        for x in 0..self.size {
+            for y in self.column_range(x) {
+                result.set(y, x, self.get(x, y));
+            }
+        }
+
+        result
+    }
+
+    pub fn to_matrix(&self) -> Matrix<K> {
+        Matrix::from_fn(self.size, self.size, |x, y| self.get(x, y))
+    }
+}
+
+impl<K: Field> ops::Mul<&Vector<K>> for &Banded<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        assert_eq!(rhs.size(), self.size, "Vector size must equal matrix size");
+        let mut result = Vector::from_elem(K::zero(), self.size);
+
+        for x in 0..self.size {
+            for y in self.column_range(x) {
+                result[y] = MulAdd::mul_add(self.get(x, y), rhs[x], result[y]);
+            }
+        }
+
+        result
+    }
+}
+
+impl<K: Field> LinearOperator<K> for Banded<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size, self.size)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self * x
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        Some(&self.transpose() * x)
+    }
+}
+
+/// PA = LU with partial pivoting. Row interchanges let U's upper bandwidth grow to
+/// `lower + upper`, as in LAPACK's `gbtrf`, and scatter L's `lower` entries per column
+/// below the diagonal. Cost is O(n·lower·(lower + upper)) to factor and
+/// O(n·(lower + upper)) per solve.
+#[derive(Debug, Clone)]
+pub struct BandedLu<K: Field> {
+    size: usize,
+    lower: usize,
+    /// Upper bandwidth of U.
+    upper: usize,
+    /// Rows of U, each holding columns `y - lower ..= y + upper`.
+    rows: Vec<K>,
+    /// Column k of L as `lower` (row of PA, multiplier) pairs.
+    multipliers: Vec<(usize, K)>,
+    permutation: Permutation,
+}
+
+impl<K: Field> BandedLu<K> {
+    fn new(a: &Banded<K>) -> Option<Self> {
+        let (n, lower) = (a.size, a.lower);
+        let mut lu = BandedLu {
+            size: n,
+            lower,
+            upper: a.lower + a.upper,
+            rows: vec![K::zero(); n * (2 * a.lower + a.upper + 1)],
+            multipliers: vec![(0, K::zero()); n * lower],
+            permutation: Permutation::identity(n),
+        };
+        for x in 0..n {
+            for y in a.column_range(x) {
+                *lu.at(x, y) = a.get(x, y);
+            }
+        }
+
+        for k in 0..n {
+            let last = (k + lower).min(n - 1);
+            let right = (k + lu.upper).min(n - 1);
+            let mut pivot = k;
+            for y in k + 1..=last {
+                if Abs::abs(*lu.at(k, y)) > Abs::abs(*lu.at(k, pivot)) {
+                    pivot = y;
+                }
+            }
+            if *lu.at(k, pivot) == K::zero() {
+                return None;
+            }
+
+            if pivot != k {
+                lu.permutation.swap(k, pivot);
+                for x in k..=right {
+                    let temp = *lu.at(x, k);
+                    *lu.at(x, k) = *lu.at(x, pivot);
+                    *lu.at(x, pivot) = temp;
+                }
+            }
+
+            for y in k + 1..=last {
+                let multiplier = *lu.at(k, y) / *lu.at(k, k);
+                // Rows may still move, so record which row of A this is
+                lu.multipliers[k * lower + y - k - 1] = (lu.permutation[y], multiplier);
+                for x in k + 1..=right {
+                    let value = *lu.at(x, y) - multiplier * *lu.at(x, k);
+                    *lu.at(x, y) = value;
+                }
+            }
+        }
+
+        let positions = lu.permutation.inverse();
+        for (row, _) in &mut lu.multipliers {
+            *row = positions[*row];
+        }
+
+        Some(lu)
+    }
+
+    fn at(&mut self, x: usize, y: usize) -> &mut K {
+        let width = self.lower + self.upper + 1;
+        &mut self.rows[y * width + x + self.lower - y]
+    }
+
+    fn get(&self, x: usize, y: usize) -> K {
+        let width = self.lower + self.upper + 1;
+        self.rows[y * width + x + self.lower - y]
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        let n = self.size;
+        assert_eq!(b.size(), n, "Vector size must equal matrix size");
+        let mut x = &self.permutation * b;
+
+        for k in 0..n {
+            let count = self.lower.min(n - 1 - k);
+            for &(y, multiplier) in &self.multipliers[k * self.lower..k * self.lower + count] {
+                x[y] = x[y] - multiplier * x[k];
+            }
+        }
+
+        for y in (0..n).rev() {
+            let mut value = x[y];
+            for column in y + 1..=(y + self.upper).min(n - 1) {
+                value = value - self.get(column, y) * x[column];
+            }
+            x[y] = value / self.get(y, y);
+        }
+
+        x
+    }
+
+    pub fn permutation(&self) -> &Permutation {
+        &self.permutation
+    }
+
+    pub fn determinant(&self) -> K {
+        (0..self.size).fold(self.permutation.sign(), |acc, i| acc * self.get(i, i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_banded_storage() {
+        let m = Matrix::from_rows([
+            [1., 4., 0., 0., 0.],
+            [3., 2., -1., 0., 0.],
+            [5., 1., 6., 2., 0.],
+            [0., -2., 0., 3., 1.],
+            [0., 0., 7., 1., 4.],
+        ]);
+        let banded = Banded::from_matrix(&m, 2, 1);
+        assert_eq!(banded.bandwidths(), (2, 1));
+        assert_eq!(banded.to_matrix(), m);
+        assert_eq!(banded.get(4, 0), 0.);
+        assert_eq!(banded.transpose().to_matrix(), m.transpose());
+        assert_eq!(Banded::from_matrix(&m, 0, 0).to_matrix(), m.band(0, 0));
+
+        let x = Vector::from([1., -1., 2., 0.5, 3.]);
+        assert_eq!(&banded * &x, &m * &x);
+        assert_eq!(banded.apply_transpose(&x), Some(&m.transpose() * &x));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_banded_set_outside() {
+        Banded::zeros(3, 1, 0).set(1, 0, 1.);
+    }
+
+    #[test]
+    fn test_banded_solve() {
+        // The leading 1 forces row swaps, so U gains an extra superdiagonal
+        let m = Matrix::from_rows([
+            [1., 4., 0., 0., 0.],
+            [3., 2., -1., 0., 0.],
+            [5., 1., 6., 2., 0.],
+            [0., -2., 0., 3., 1.],
+            [0., 0., 7., 1., 4.],
+        ]);
+        let banded = Banded::from_matrix(&m, 2, 1);
+        let x = Vector::from([1., -1., 2., 0.5, 3.]);
+        let solution = banded.solve(&(&m * &x)).unwrap();
+        assert!((solution - x).norm() < 1e-12);
+
+        let lu = banded.factor().unwrap();
+        assert_ne!(lu.permutation(), &Permutation::identity(5));
+        let b = Vector::from([1., 0., 0., 0., 0.]);
+        assert!((&m * &lu.solve(&b) - b).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_banded_solve_pivot_chain() {
+        // Tiny diagonals push each row down again and again, so L's entries move far from
+        // where they were computed
+        let m = Matrix::from_fn(12, 12, |x, y| match x as isize - y as isize {
+            0 => 1e-3,
+            -1 => 2. + y as f64,
+            -2 => 1. - x as f64 * 0.5,
+            1 => 0.5,
+            _ => 0.,
+        });
+        let banded = Banded::from_matrix(&m, 2, 1);
+        let x = Vector::from_fn(12, |i| (i as f64 * 0.7).cos());
+        let solution = banded.solve(&(&m * &x)).unwrap();
+        assert!((solution - x).norm() < 1e-9);
+    }
+
+    #[test]
+    fn test_banded_determinant() {
+        let m = Matrix::from_rows([
+            [0., 2., 0., 0.],
+            [1., 1., 3., 0.],
+            [0., 4., 1., 1.],
+            [0., 0., 2., 5.],
+        ]);
+        let banded = Banded::from_matrix(&m, 1, 1);
+        assert!((banded.determinant() - m.determinant()).abs() < 1e-12);
+
+        let singular = Banded::from_matrix(&Matrix::from_rows([
+            [1., 2., 0.],
+            [2., 4., 0.],
+            [0., 0., 1.],
+        ]), 1, 1);
+        assert!(singular.factor().is_none());
+        assert_eq!(singular.determinant(), 0.);
+        assert_eq!(singular.solve(&Vector::from([1., 1., 1.])), None);
+    }
+}
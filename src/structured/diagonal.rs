@@ -0,0 +1,120 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::operator::LinearOperator;
+use crate::traits::Field;
+use std::ops;
+
+/// A square diagonal matrix, storing only its diagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagonal<K: Field> {
+    values: Vector<K>,
+}
+
+impl<K: Field> Diagonal<K> {
+    pub fn new(values: Vector<K>) -> Self {
+        Diagonal { values }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        Diagonal::new(Vector::from_elem(K::one(), size))
+    }
+
+    pub fn size(&self) -> usize {
+        self.values.size()
+    }
+
+    pub fn values(&self) -> &Vector<K> {
+        &self.values
+    }
+
+    /// Solves `D x = b`, or returns `None` when a diagonal element is zero.
+    pub fn solve(&self, b: &Vector<K>) -> Option<Vector<K>> {
+        assert_eq!(b.size(), self.size(), "Vector size must equal matrix size");
+        if self.values.any(|value| value == K::zero()) {
+            return None;
+        }
+
+        Some(b.elementwise_div(&self.values))
+    }
+
+    pub fn determinant(&self) -> K {
+        self.values.fold(K::one(), |acc, value| acc * value)
+    }
+
+    pub fn inverse(&self) -> Option<Diagonal<K>> {
+        self.solve(&Vector::from_elem(K::one(), self.size())).map(Diagonal::new)
+    }
+
+    pub fn to_matrix(&self) -> Matrix<K> {
+        Matrix::from_diagonal(&self.values)
+    }
+}
+
+/// Keeps the main diagonal of a square matrix.
+impl<K: Field> From<&Matrix<K>> for Diagonal<K> {
+    fn from(value: &Matrix<K>) -> Self {
+        assert!(value.is_square(), "Diagonal matrices must be square");
+        Diagonal::new(value.diagonal(0))
+    }
+}
+
+impl<K: Field> ops::Mul<&Vector<K>> for &Diagonal<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        assert_eq!(rhs.size(), self.size(), "Vector size must equal matrix size");
+        self.values.hadamard(rhs)
+    }
+}
+
+impl<K: Field> ops::Mul<&Diagonal<K>> for &Diagonal<K> {
+    type Output = Diagonal<K>;
+
+    fn mul(self, rhs: &Diagonal<K>) -> Self::Output {
+        assert_eq!(self.size(), rhs.size(), "Diagonal matrix sizes mismatch.");
+        Diagonal::new(self.values.hadamard(&rhs.values))
+    }
+}
+
+impl<K: Field> LinearOperator<K> for Diagonal<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self * x
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        Some(self * x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagonal() {
+        let d = Diagonal::new(Vector::from([2., -1., 4.]));
+        assert_eq!(&d * &Vector::from([1., 2., 3.]), Vector::from([2., -2., 12.]));
+        assert_eq!(d.determinant(), -8.);
+        assert_eq!(d.solve(&Vector::from([1., 1., 1.])), Some(Vector::from([0.5, -1., 0.25])));
+        assert_eq!((&d * &d.inverse().unwrap()), Diagonal::identity(3));
+        assert_eq!(d.to_matrix(), Matrix::from_rows([
+            [2., 0., 0.],
+            [0., -1., 0.],
+            [0., 0., 4.],
+        ]));
+        assert_eq!(Diagonal::from(&d.to_matrix()), d);
+    }
+
+    #[test]
+    fn test_diagonal_singular() {
+        let d = Diagonal::new(Vector::from([1., 0.]));
+        assert_eq!(d.solve(&Vector::from([1., 1.])), None);
+        assert_eq!(d.inverse(), None);
+        assert_eq!(d.determinant(), 0.);
+    }
+}
@@ -0,0 +1,14 @@
+mod banded;
+mod diagonal;
+mod triangular;
+mod tridiagonal;
+
+#[allow(unused_imports)]
+pub use banded::*;
+#[allow(unused_imports)]
+pub use diagonal::*;
+pub use crate::core::Permutation;
+#[allow(unused_imports)]
+pub use triangular::*;
+#[allow(unused_imports)]
+pub use tridiagonal::*;
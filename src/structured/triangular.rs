@@ -0,0 +1,197 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::operator::LinearOperator;
+use crate::traits::{Field, MulAdd};
+use std::ops;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Triangle {
+    Upper,
+    Lower,
+}
+
+/// A square triangular matrix, packed column by column: n(n + 1)/2 elements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Triangular<K: Field> {
+    size: usize,
+    triangle: Triangle,
+    data: Vec<K>,
+}
+
+impl<K: Field> Triangular<K> {
+    /// Keeps the given triangle of a square matrix, diagonal included.
+    pub fn new(matrix: &Matrix<K>, triangle: Triangle) -> Self {
+        assert!(matrix.is_square(), "Triangular matrices must be square");
+        let size = matrix.shape().0;
+        let mut result = Triangular { size, triangle, data: Vec::with_capacity(size * (size + 1) / 2) };
+
+        for x in 0..size {
+            for y in result.column_range(x) {
+                result.data.push(matrix[(x, y)]);
+            }
+        }
+
+        result
+    }
+
+    pub fn upper(matrix: &Matrix<K>) -> Self {
+        Triangular::new(matrix, Triangle::Upper)
+    }
+
+    pub fn lower(matrix: &Matrix<K>) -> Self {
+        Triangular::new(matrix, Triangle::Lower)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn triangle(&self) -> Triangle {
+        self.triangle
+    }
+
+    /// Rows of column `x` inside the triangle.
+    fn column_range(&self, x: usize) -> ops::Range<usize> {
+        match self.triangle {
+            Triangle::Upper => 0..x + 1,
+            Triangle::Lower => x..self.size,
+        }
+    }
+
+    fn position(&self, x: usize, y: usize) -> Option<usize> {
+        assert!(x < self.size && y < self.size);
+        match self.triangle {
+            Triangle::Upper if y <= x => Some(x * (x + 1) / 2 + y),
+            Triangle::Lower if y >= x => Some(x * (2 * self.size - x + 1) / 2 + y - x),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> K {
+        self.position(x, y).map_or(K::zero(), |i| self.data[i])
+    }
+
+    /// Solves `T x = b` by forward or back substitution, or returns `None` when a
+    /// diagonal element is zero.
+    pub fn solve(&self, b: &Vector<K>) -> Option<Vector<K>> {
+        assert_eq!(b.size(), self.size, "Vector size must equal matrix size");
+        if (0..self.size).any(|i| self.get(i, i) == K::zero()) {
+            return None;
+        }
+
+        let mut x = b.clone();
+        // Column-oriented: once x[j] is known, remove its contribution from the rest
+        let columns: Box<dyn Iterator<Item = usize>> = match self.triangle {
+            Triangle::Lower => Box::new(0..self.size),
+            Triangle::Upper => Box::new((0..self.size).rev()),
+        };
+        for j in columns {
+            x[j] = x[j] / self.get(j, j);
+            for i in self.column_range(j).filter(|&i| i != j) {
+                x[i] = x[i] - self.get(j, i) * x[j];
+            }
+        }
+
+        Some(x)
+    }
+
+    pub fn determinant(&self) -> K {
+        (0..self.size).fold(K::one(), |acc, i| acc * self.get(i, i))
+    }
+
+    pub fn transpose(&self) -> Triangular<K> {
+        let triangle = match self.triangle {
+            Triangle::Upper => Triangle::Lower,
+            Triangle::Lower => Triangle::Upper,
+        };
+        Triangular::new(&self.to_matrix().transpose(), triangle)
+    }
+
+    pub fn to_matrix(&self) -> Matrix<K> {
+        Matrix::from_fn(self.size, self.size, |x, y| self.get(x, y))
+    }
+}
+
+impl<K: Field> ops::Mul<&Vector<K>> for &Triangular<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        assert_eq!(rhs.size(), self.size, "Vector size must equal matrix size");
+        let mut result = Vector::from_elem(K::zero(), self.size);
+
+        for x in 0..self.size {
+            for y in self.column_range(x) {
+                result[y] = MulAdd::mul_add(self.get(x, y), rhs[x], result[y]);
+            }
+        }
+
+        result
+    }
+}
+
+impl<K: Field> LinearOperator<K> for Triangular<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size, self.size)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self * x
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        Some(Vector::from_fn(self.size, |y| {
+            self.column_range(y).fold(K::zero(), |acc, i| MulAdd::mul_add(self.get(y, i), x[i], acc))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangular_storage() {
+        let a = Matrix::from_rows([
+            [2., 1., -1.],
+            [4., 3., 5.],
+            [-2., 6., 4.],
+        ]);
+        let lower = Triangular::lower(&a);
+        assert_eq!(lower.data, [2., 4., -2., 3., 6., 4.]);
+        assert_eq!(lower.to_matrix(), a.lower_triangular(0));
+
+        let upper = Triangular::upper(&a);
+        assert_eq!(upper.data, [2., 1., 3., -1., 5., 4.]);
+        assert_eq!(upper.to_matrix(), a.upper_triangular(0));
+        assert_eq!(upper.get(0, 2), 0.);
+        assert_eq!(upper.transpose().to_matrix(), a.upper_triangular(0).transpose());
+    }
+
+    #[test]
+    fn test_triangular_solve() {
+        let a = Matrix::from_rows([
+            [2., 1., -1.],
+            [4., 3., 5.],
+            [-2., 6., 4.],
+        ]);
+        let x = Vector::from([1., -2., 3.]);
+        for triangular in [Triangular::lower(&a), Triangular::upper(&a)] {
+            let b = &triangular * &x;
+            assert_eq!(b, &triangular.to_matrix() * &x);
+            assert_eq!(triangular.solve(&b), Some(x.clone()));
+            assert_eq!(triangular.determinant(), 24.);
+            assert_eq!(triangular.apply_transpose(&x), Some(&triangular.to_matrix().transpose() * &x));
+        }
+    }
+
+    #[test]
+    fn test_triangular_singular() {
+        let singular = Triangular::upper(&Matrix::from_rows([
+            [1., 2.],
+            [0., 0.],
+        ]));
+        assert_eq!(singular.solve(&Vector::from([1., 1.])), None);
+        assert_eq!(singular.determinant(), 0.);
+    }
+}
@@ -0,0 +1,199 @@
+#![allow(dead_code)]
+
+use crate::core::{Matrix, Vector};
+use crate::operator::LinearOperator;
+use crate::traits::{Field, MulAdd};
+use std::ops;
+
+/// A square tridiagonal matrix, stored as its three diagonals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal<K: Field> {
+    lower: Vector<K>,
+    diagonal: Vector<K>,
+    upper: Vector<K>,
+}
+
+impl<K: Field> Tridiagonal<K> {
+    /// `lower` and `upper` are the sub- and superdiagonal, one element shorter than `diagonal`.
+    pub fn new(lower: Vector<K>, diagonal: Vector<K>, upper: Vector<K>) -> Self {
+        assert!(diagonal.size() > 0, "Tridiagonal matrices must not be empty");
+        assert_eq!(lower.size() + 1, diagonal.size(), "Subdiagonal must be one shorter than the diagonal");
+        assert_eq!(upper.size() + 1, diagonal.size(), "Superdiagonal must be one shorter than the diagonal");
+        Tridiagonal { lower, diagonal, upper }
+    }
+
+    pub fn size(&self) -> usize {
+        self.diagonal.size()
+    }
+
+    pub fn lower(&self) -> &Vector<K> {
+        &self.lower
+    }
+
+    pub fn diagonal(&self) -> &Vector<K> {
+        &self.diagonal
+    }
+
+    pub fn upper(&self) -> &Vector<K> {
+        &self.upper
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> K {
+        assert!(x < self.size() && y < self.size());
+        match x as isize - y as isize {
+            0 => self.diagonal[y],
+            -1 => self.lower[x],
+            1 => self.upper[y],
+            _ => K::zero(),
+        }
+    }
+
+    /// Solves `T x = b` with the Thomas algorithm in O(n). It does not pivot, so it returns
+    /// `None` on a zero pivot; diagonally dominant systems never have one.
+    pub fn solve(&self, b: &Vector<K>) -> Option<Vector<K>> {
+        let n = self.size();
+        assert_eq!(b.size(), n, "Vector size must equal matrix size");
+
+        // Forward sweep: eliminate the subdiagonal, normalizing each row's pivot to one
+        let mut upper = Vector::from_elem(K::zero(), n);
+        let mut x = b.clone();
+        for i in 0..n {
+            let (pivot, rhs) = match i {
+                0 => (self.diagonal[0], x[0]),
+                _ => (
+                    self.diagonal[i] - self.lower[i - 1] * upper[i - 1],
+                    x[i] - self.lower[i - 1] * x[i - 1],
+                ),
+            };
+            if pivot == K::zero() {
+                return None;
+            }
+            if i + 1 < n {
+                upper[i] = self.upper[i] / pivot;
+            }
+            x[i] = rhs / pivot;
+        }
+
+        for i in (0..n - 1).rev() {
+            x[i] = x[i] - upper[i] * x[i + 1];
+        }
+
+        Some(x)
+    }
+
+    /// Computed with the three-term continuant recurrence, which needs no pivots.
+    pub fn determinant(&self) -> K {
+        let (mut previous, mut current) = (K::one(), self.diagonal[0]);
+
+        for i in 1..self.size() {
+            let next = self.diagonal[i] * current - self.lower[i - 1] * self.upper[i - 1] * previous;
+            (previous, current) = (current, next);
+        }
+
+        current
+    }
+
+    pub fn transpose(&self) -> Tridiagonal<K> {
+        Tridiagonal::new(self.upper.clone(), self.diagonal.clone(), self.lower.clone())
+    }
+
+    pub fn to_matrix(&self) -> Matrix<K> {
+        Matrix::from_fn(self.size(), self.size(), |x, y| self.get(x, y))
+    }
+}
+
+/// Keeps the three central diagonals of a square matrix.
+impl<K: Field> From<&Matrix<K>> for Tridiagonal<K> {
+    fn from(value: &Matrix<K>) -> Self {
+        assert!(value.is_square(), "Tridiagonal matrices must be square");
+        Tridiagonal::new(value.diagonal(-1), value.diagonal(0), value.diagonal(1))
+    }
+}
+
+impl<K: Field> ops::Mul<&Vector<K>> for &Tridiagonal<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        let n = self.size();
+        assert_eq!(rhs.size(), n, "Vector size must equal matrix size");
+
+        Vector::from_fn(n, |i| {
+            let mut value = self.diagonal[i] * rhs[i];
+            if i > 0 {
+                value = MulAdd::mul_add(self.lower[i - 1], rhs[i - 1], value);
+            }
+            if i + 1 < n {
+                value = MulAdd::mul_add(self.upper[i], rhs[i + 1], value);
+            }
+            value
+        })
+    }
+}
+
+impl<K: Field> LinearOperator<K> for Tridiagonal<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self * x
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Option<Vector<K>> {
+        Some(&self.transpose() * x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tridiagonal_matrix() {
+        let t: Tridiagonal<f64> = Tridiagonal::new(Vector::from([1., -1., 2.]), Vector::from([4., 4., 5., 3.]), Vector::from([2., 1., 1.]));
+        let m = Matrix::from_rows([
+            [4., 2., 0., 0.],
+            [1., 4., 1., 0.],
+            [0., -1., 5., 1.],
+            [0., 0., 2., 3.],
+        ]);
+        assert_eq!(t.to_matrix(), m);
+        assert_eq!(Tridiagonal::from(&m), t);
+
+        let x = Vector::from([1., 2., -1., 3.]);
+        assert_eq!(&t * &x, &m * &x);
+        assert_eq!(t.apply_transpose(&x), Some(&m.transpose() * &x));
+        assert_eq!(t.determinant(), 194.);
+        assert!((m.determinant() - 194.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_thomas() {
+        let t = Tridiagonal::new(Vector::from([1., -1., 2.]), Vector::from([4., 4., 5., 3.]), Vector::from([2., 1., 1.]));
+        let x = Vector::from([1., 2., -1., 3.]);
+        let solution = t.solve(&(&t * &x)).unwrap();
+        assert!((solution - x).norm() < 1e-12);
+
+        // Second-difference matrix from a 1-D Poisson problem
+        let n = 50;
+        let poisson = Tridiagonal::new(
+            Vector::from_elem(-1., n - 1),
+            Vector::from_elem(2., n),
+            Vector::from_elem(-1., n - 1),
+        );
+        let x = Vector::from_fn(n, |i| (i as f64 * 0.3).sin());
+        let solution = poisson.solve(&(&poisson * &x)).unwrap();
+        assert!((solution - x).norm() < 1e-9);
+        assert!((poisson.determinant() - (n + 1) as f64).abs() < 1e-9);
+
+        let single = Tridiagonal::new(Vector::from([]), Vector::from([4.]), Vector::from([]));
+        assert_eq!(single.solve(&Vector::from([2.])), Some(Vector::from([0.5])));
+    }
+
+    #[test]
+    fn test_thomas_zero_pivot() {
+        let t = Tridiagonal::new(Vector::from([1.]), Vector::from([0., 1.]), Vector::from([1.]));
+        assert_eq!(t.solve(&Vector::from([1., 1.])), None);
+        assert_eq!(t.determinant(), -1.);
+    }
+}
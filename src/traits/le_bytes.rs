@@ -0,0 +1,61 @@
+pub trait LeBytes: Sized + Copy {
+    const SIZE: usize;
+
+    fn write_le(self, out: &mut Vec<u8>);
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_le_bytes {
+    ($($t:ty),*) => {
+        $(
+        impl LeBytes for $t {
+            const SIZE: usize = size_of::<$t>();
+
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_le(bytes: &[u8]) -> Self {
+                let mut buffer = [0; size_of::<$t>()];
+                buffer.copy_from_slice(&bytes[..size_of::<$t>()]);
+                <$t>::from_le_bytes(buffer)
+            }
+        }
+        )*
+    };
+}
+
+impl_le_bytes!(f32, f64);
+impl_le_bytes!(i8, i16, i32, i64, i128);
+impl_le_bytes!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_le_bytes {
+        ($i:ident, $t:ty, $value:expr) => {
+            #[test]
+            fn $i() {
+                let mut out = Vec::new();
+                LeBytes::write_le($value as $t, &mut out);
+                assert_eq!(out.len(), <$t as LeBytes>::SIZE);
+                assert_eq!(out, ($value as $t).to_le_bytes());
+                assert_eq!(<$t as LeBytes>::read_le(&out), $value as $t);
+            }
+        };
+    }
+
+    test_le_bytes!(le_bytes_f32, f32, -1.5);
+    test_le_bytes!(le_bytes_f64, f64, 1234.5678);
+    test_le_bytes!(le_bytes_i8, i8, -100);
+    test_le_bytes!(le_bytes_i16, i16, -30000);
+    test_le_bytes!(le_bytes_i32, i32, -7);
+    test_le_bytes!(le_bytes_i64, i64, 1i64 << 40);
+    test_le_bytes!(le_bytes_i128, i128, -(1i128 << 100));
+    test_le_bytes!(le_bytes_u8, u8, 200);
+    test_le_bytes!(le_bytes_u16, u16, 60000);
+    test_le_bytes!(le_bytes_u32, u32, 4_000_000_000u32);
+    test_le_bytes!(le_bytes_u64, u64, u64::MAX);
+    test_le_bytes!(le_bytes_u128, u128, u128::MAX);
+}
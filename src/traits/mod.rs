@@ -4,10 +4,14 @@ mod zero;
 mod one;
 mod sqrt;
 mod abs;
+mod le_bytes;
+mod to_f64;
 
 pub use abs::*;
 pub use field::*;
+pub use le_bytes::*;
 pub use muladd::*;
 pub use one::*;
 pub use sqrt::*;
+pub use to_f64::*;
 pub use zero::*;
@@ -0,0 +1,44 @@
+/// Lossy conversion used where an algorithm needs real arithmetic, such as mapping values to colors.
+pub trait ToF64: Copy {
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty),*) => {
+        $(
+        impl ToF64 for $t {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+        )*
+    };
+}
+
+impl_to_f64!(f32, f64);
+impl_to_f64!(i8, i16, i32, i64, i128);
+impl_to_f64!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_to_f64 {
+        ($i:ident, $t:ty, $value:expr) => {
+            #[test]
+            fn $i() {
+                assert_eq!(ToF64::to_f64($value as $t), $value as f64);
+            }
+        };
+    }
+
+    test_to_f64!(to_f64_f32, f32, -1.5);
+    test_to_f64!(to_f64_f64, f64, 1234.5678);
+    test_to_f64!(to_f64_i8, i8, -100);
+    test_to_f64!(to_f64_i16, i16, -30000);
+    test_to_f64!(to_f64_i32, i32, 1 << 30);
+    test_to_f64!(to_f64_i64, i64, -(1i64 << 40));
+    test_to_f64!(to_f64_i128, i128, 1i128 << 100);
+    test_to_f64!(to_f64_u8, u8, 255);
+    test_to_f64!(to_f64_u64, u64, u64::MAX);
+}